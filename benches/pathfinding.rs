@@ -0,0 +1,51 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use hextile::{AxialCoords, HexCoords, HexMap, area_len};
+
+
+/// Builds a hexagonal map with the given radius, where each tile stores the cost of moving onto it
+fn cost_map(radius: usize) -> HexMap<AxialCoords, f32>
+{
+    let mut map = HexMap::new();
+    for coords in AxialCoords::area_iter(AxialCoords::ZERO, radius)
+    {
+        let cost = (coords.q * 7 + coords.r * 13).rem_euclid(3) + 1;
+        map.insert(coords, cost as f32);
+    }
+    map
+}
+
+fn cost_fn(_from: AxialCoords, to: AxialCoords, map: &HexMap<AxialCoords, f32>) -> f32
+{
+    *map.get(to).unwrap()
+}
+
+/// Finds paths between opposite corners of maps of roughly 1k, 10k and 100k tiles
+fn find_path(c: &mut Criterion)
+{
+    let mut group = c.benchmark_group("find_path");
+    group.sample_size(10);
+    for radius in [18, 57, 182]
+    {
+        let map = cost_map(radius);
+        let start = AxialCoords::new(-(radius as isize), 0);
+        let end = AxialCoords::new(radius as isize, 0);
+        let tiles = area_len(radius);
+        group.bench_with_input(BenchmarkId::new("dijkstra", tiles), &map, |b, map| {
+            b.iter(|| map.find_path(start, end, cost_fn))
+        });
+        group.bench_with_input(BenchmarkId::new("astar", tiles), &map, |b, map| {
+            b.iter(|| map.find_path_astar(start, end, 1.0, cost_fn))
+        });
+        group.bench_with_input(BenchmarkId::new("bidirectional", tiles), &map, |b, map| {
+            b.iter(|| map.find_path_bidirectional(start, end, 0.0, cost_fn))
+        });
+        let graph = map.cluster_graph(8, cost_fn);
+        group.bench_with_input(BenchmarkId::new("hierarchical", tiles), &map, |b, map| {
+            b.iter(|| graph.find_path(map, start, end, cost_fn))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, find_path);
+criterion_main!(benches);
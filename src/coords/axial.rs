@@ -1,112 +1,85 @@
-use std::ops::{Add, Sub, Mul};
+use std::ops::{Add, Mul, Neg, Sub};
 use serde::{Deserialize, Serialize};
-use crate::{CubeCoords, Orientation, HexCoords, axial};
+use crate::{CoordInt, Cube, FractionalAxial, Orientation, HexCoords};
 
 
 
 /// Axial coordinate system
 /// 
-/// Good for general use. Intuitive for humans, and cheaply converts to/from [`CubeCoords`] which are good for math and other operations
+/// Good for general use. Intuitive for humans, and cheaply converts to/from [`CubeCoords`](crate::CubeCoords) which are good for math and other operations
 /// 
 /// <https://www.redblobgames.com/grids/hexagons/#coordinates-axial>
+pub type AxialCoords = Axial<isize>;
+
+/// [`AxialCoords`] with `i16` components
+pub type AxialCoords16 = Axial<i16>;
+
+/// [`AxialCoords`] with `i32` components
+pub type AxialCoords32 = Axial<i32>;
+
+/// [`AxialCoords`] with `i64` components
+pub type AxialCoords64 = Axial<i64>;
+
+/// [`AxialCoords`] with components of any [`CoordInt`] type `I`
+/// 
+/// Most code should use [`AxialCoords`] or one of the aliases for a specific integer type, which
+/// are all forms of this type.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[derive(Deserialize, Serialize)]
-pub struct AxialCoords
+#[serde(bound = "I: CoordInt", rename = "AxialCoords")]
+pub struct Axial<I>
 {
-    pub q: isize,
-    pub r: isize,
+    pub q: I,
+    pub r: I,
 }
 
 
-impl AxialCoords
+impl<I: CoordInt> Axial<I>
 {
-    pub const ZERO: AxialCoords = AxialCoords{ q: 0, r: 0 };
+    pub const ZERO: Self = Self{ q: I::ZERO, r: I::ZERO };
 
-    pub const Q: AxialCoords = AxialCoords{ q: 1, r: 0};
+    pub const Q: Self = Self{ q: I::ONE, r: I::ZERO };
 
-    pub const R: AxialCoords = AxialCoords{ q: 0, r: 1 };
+    pub const R: Self = Self{ q: I::ZERO, r: I::ONE };
 
-    pub const S: AxialCoords = AxialCoords{ q: -1, r: 1 };
+    pub const S: Self = Self{ q: I::NEG_ONE, r: I::ONE };
 
-    pub fn new(q: isize, r: isize) -> Self
+    pub fn new(q: I, r: I) -> Self
     {
         Self{ q, r }
     }
 
-    pub fn distance(a: Self, b: Self) -> isize
+    pub fn distance(a: Self, b: Self) -> I
     {
-        CubeCoords::distance(CubeCoords::from(a), CubeCoords::from(b))
+        Cube::distance(Cube::from(a), Cube::from(b))
     }
 }
 
-impl HexCoords for AxialCoords
+impl<I: CoordInt> HexCoords for Axial<I>
 {
-    fn line(a: Self, b: Self) -> Vec<Self> {
-        let cube_line = CubeCoords::line(a.into(), b.into());
-        cube_line.iter().map(|val|{AxialCoords::from(val)}).collect()
-    }
+    type Int = I;
 
-    fn ring(center: Self, radius: usize) -> Vec<Self> {
-        if radius == 0 {
-            return vec![center];
-        }
-        let mut ring = Vec::new();
-        for i in 0..radius {
-            ring.push(center + AxialCoords::Q * radius + AxialCoords::S * i);
-            ring.push(center + AxialCoords::R * radius - AxialCoords::Q * i);
-            ring.push(center + AxialCoords::S * radius - AxialCoords::R * i);
-            ring.push(center - AxialCoords::Q * radius - AxialCoords::S * i);
-            ring.push(center - AxialCoords::R * radius + AxialCoords::Q * i);
-            ring.push(center - AxialCoords::S * radius + AxialCoords::R * i);
-        }
-        ring
+    fn to_cube(&self) -> Cube<I> {
+        Cube::from(*self)
     }
 
-    fn adjacent(center: Self) -> Vec<Self> {
-        vec![
-            center + axial!(0, -1),
-            center + axial!(1, -1),
-            center + axial!(1, 0),
-            center + axial!(0, 1),
-            center + axial!(-1, 1),
-            center + axial!(-1, 0),
-        ]
+    fn from_cube(cube: Cube<I>) -> Self {
+        Self::from(cube)
     }
 
     fn from_world(x: f32, y: f32, orientation: Orientation) -> Self {
-        let sqrt_3 = 3.0f32.sqrt();
-        match orientation
-        {
-            Orientation::PointyTop => {
-                let q = sqrt_3 / 3.0 * x - 1.0 / 3.0 * y;
-                let r = 2.0 / 3.0 * y;
-                let s = -q - r;
-                let cube = CubeCoords::round(q, r, s);
-                AxialCoords::from(cube)
-            },
-            Orientation::FlatTop => todo!(),
-        }
+        FractionalAxial::from_world(x, y, orientation).round()
     }
 
     fn to_world(&self, orientation: Orientation) -> (f32, f32)
     {
-        match orientation
-        {
-            Orientation::FlatTop => {
-                todo!()
-            },
-            Orientation::PointyTop => {
-                let x = self.q as f32 * orientation.tile_width() + self.r as f32 * orientation.tile_width() / 2.0;
-                let y = self.r as f32 * orientation.tile_spacing_y();
-                (x, y)
-            },
-        }
+        FractionalAxial::from(*self).to_world(orientation)
     }
 }
 
 // TRAITS: MATH OPERATIONS ---------------------------------------------------------------------- //
 
-impl Add<Self> for AxialCoords
+impl<I: CoordInt> Add<Self> for Axial<I>
 {
     type Output = Self;
 
@@ -115,17 +88,27 @@ impl Add<Self> for AxialCoords
     }
 }
 
-impl Mul<usize> for AxialCoords
+impl<I: CoordInt> Mul<usize> for Axial<I>
 {
-    type Output = AxialCoords;
+    type Output = Axial<I>;
 
     fn mul(self, rhs: usize) -> Self::Output
     {
-        Self{ q: self.q * rhs as isize, r: self.r * rhs as isize }
+        let rhs = I::from_isize(rhs as isize);
+        Self{ q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl<I: CoordInt> Neg for Axial<I>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self{ q: -self.q, r: -self.r }
     }
 }
 
-impl Sub<Self> for AxialCoords
+impl<I: CoordInt> Sub<Self> for Axial<I>
 {
     type Output = Self;
 
@@ -136,22 +119,22 @@ impl Sub<Self> for AxialCoords
 
 // TRAITS: CONVERSION --------------------------------------------------------------------------- //
 
-impl From<CubeCoords> for AxialCoords
+impl<I: CoordInt> From<Cube<I>> for Axial<I>
 {
-    /// Converts to [`AxialCoords`] from [`CubeCoords`]
+    /// Converts to [`AxialCoords`] from [`CubeCoords`](crate::CubeCoords)
     /// 
     /// <https://www.redblobgames.com/grids/hexagons/#conversions-axial>
-    fn from(value: CubeCoords) -> Self {
+    fn from(value: Cube<I>) -> Self {
         Self{ q: value.q, r: value.r }
     }
 }
 
-impl From<&CubeCoords> for AxialCoords
+impl<I: CoordInt> From<&Cube<I>> for Axial<I>
 {
-    /// Converts to [`AxialCoords`] from [`&CubeCoords`](CubeCoords)
+    /// Converts to [`AxialCoords`] from [`&CubeCoords`](crate::CubeCoords)
     /// 
     /// <https://www.redblobgames.com/grids/hexagons/#conversions-axial>
-    fn from(value: &CubeCoords) -> Self {
+    fn from(value: &Cube<I>) -> Self {
         Self{ q: value.q, r: value.r }
     }
 }
@@ -163,7 +146,7 @@ impl From<&CubeCoords> for AxialCoords
 mod tests
 {
     use super::*;
-    use crate::{axial, cube};
+    use crate::{axial, cube, CubeCoords, HexAxis};
 
     #[test]
     fn add()
@@ -198,6 +181,27 @@ mod tests
         assert_eq!(axial!(1, -1), AxialCoords::from(cube!(1, -1, 0)));
     }
 
+    /// Ensures that rotation and reflection through [`HexCoords`] match [`CubeCoords`](crate::CubeCoords)
+    #[test]
+    fn rotate_and_reflect()
+    {
+        let center = axial!(-1, 2);
+        for coords in AxialCoords::area(center, 2)
+        {
+            let cube = CubeCoords::from(coords);
+            assert_eq!(AxialCoords::from(cube.rotate_clockwise(2)), coords.rotate_clockwise(2));
+            assert_eq!(AxialCoords::from(cube.rotate_counterclockwise(1)), coords.rotate_counterclockwise(1));
+            assert_eq!(
+                AxialCoords::from(cube.rotate_clockwise_around(center.into(), 1)),
+                coords.rotate_clockwise_around(center, 1),
+            );
+            assert_eq!(
+                AxialCoords::from(cube.reflect_around(center.into(), HexAxis::R)),
+                coords.reflect_around(center, HexAxis::R),
+            );
+        }
+    }
+
     mod ops
     {
         use super::*;
@@ -216,6 +220,65 @@ mod tests
         mod hex_coords
         {
             use super::*;
+            use crate::test_utils::assert_world_eq;
+
+            #[test]
+            fn to_world_pointy_top()
+            {
+                let orientation = Orientation::PointyTop;
+                let sqrt_3 = 3.0f32.sqrt();
+                assert_world_eq((0.0, 0.0), axial!(0, 0).to_world(orientation));
+                assert_world_eq((sqrt_3, 0.0), axial!(1, 0).to_world(orientation));
+                assert_world_eq((sqrt_3 / 2.0, 1.5), axial!(0, 1).to_world(orientation));
+                assert_world_eq((-sqrt_3 / 2.0, 1.5), axial!(-1, 1).to_world(orientation));
+                assert_world_eq((sqrt_3 / 2.0, -1.5), axial!(1, -1).to_world(orientation));
+            }
+
+            #[test]
+            fn to_world_flat_top()
+            {
+                let orientation = Orientation::FlatTop;
+                let sqrt_3 = 3.0f32.sqrt();
+                assert_world_eq((0.0, 0.0), axial!(0, 0).to_world(orientation));
+                assert_world_eq((1.5, sqrt_3 / 2.0), axial!(1, 0).to_world(orientation));
+                assert_world_eq((0.0, sqrt_3), axial!(0, 1).to_world(orientation));
+                assert_world_eq((-1.5, sqrt_3 / 2.0), axial!(-1, 1).to_world(orientation));
+                assert_world_eq((1.5, -sqrt_3 / 2.0), axial!(1, -1).to_world(orientation));
+            }
+
+            /// Ensures that converting coordinates to world space and back results in the original
+            /// coordinates, for both orientations
+            #[test]
+            fn world_round_trip()
+            {
+                for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+                {
+                    for coords in AxialCoords::area(AxialCoords::ZERO, 5)
+                    {
+                        let (x, y) = coords.to_world(orientation);
+                        assert_eq!(coords, AxialCoords::from_world(x, y, orientation), "{:?}", orientation);
+                        // points slightly off center should still resolve to the same tile
+                        assert_eq!(coords, AxialCoords::from_world(x + 0.3, y - 0.3, orientation), "{:?}", orientation);
+                    }
+                }
+            }
+
+            /// Ensures that the corners of neighboring tiles line up with each other
+            #[test]
+            fn corners_shared()
+            {
+                for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+                {
+                    let center = AxialCoords::ZERO.corners(orientation);
+                    for neighbor in AxialCoords::adjacent(AxialCoords::ZERO)
+                    {
+                        let shared = neighbor.corners(orientation).iter()
+                            .filter(|a| center.iter().any(|b| (a.0 - b.0).abs() < 0.0001 && (a.1 - b.1).abs() < 0.0001))
+                            .count();
+                        assert_eq!(2, shared, "{:?} {:?}", orientation, neighbor);
+                    }
+                }
+            }
 
             #[test]
             fn ring()
@@ -1,50 +1,132 @@
 use std::{ops::{Add, Mul, Neg, Sub}, fmt::Display};
 use lerp::Lerp;
 use serde::{Deserialize, Serialize};
-use crate::{AxialCoords, HexCoords, cube, Orientation};
+use crate::{Axial, CoordInt, CoordsError, FractionalCube, HexAxis, HexCoords, Orientation};
 
 
 /// Cube coordinates
 /// 
-/// Good for math, but can be annoying to work with from a human perspective as well as having an "unnecessary" third coordinate compared to [`AxialCoords`]
+/// Good for math, but can be annoying to work with from a human perspective as well as having an "unnecessary" third coordinate compared to [`AxialCoords`](crate::AxialCoords)
 /// 
 /// <https://www.redblobgames.com/grids/hexagons/#coordinates-cube>
+pub type CubeCoords = Cube<isize>;
+
+/// [`CubeCoords`] with `i16` components
+pub type CubeCoords16 = Cube<i16>;
+
+/// [`CubeCoords`] with `i32` components
+pub type CubeCoords32 = Cube<i32>;
+
+/// [`CubeCoords`] with `i64` components
+pub type CubeCoords64 = Cube<i64>;
+
+/// [`CubeCoords`] with components of any [`CoordInt`] type `I`
+/// 
+/// Most code should use [`CubeCoords`] or one of the aliases for a specific integer type, which
+/// are all forms of this type.
+/// 
+/// Deserializing cube coordinates fails if the coordinates aren't valid (see
+/// [`Cube::is_valid`]), so data from save files or other sources can't be used to produce
+/// invalid coordinates.
+/// 
+/// Adding, subtracting, negating and scaling valid coordinates always gives valid coordinates, so
+/// the `+`, `-` and `*` operators only panic if a component overflows. Use [`Cube::checked_add`],
+/// [`Cube::checked_sub`], [`Cube::checked_neg`] and [`Cube::checked_mul`] where that might happen.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[derive(Deserialize, Serialize)]
-pub struct CubeCoords
+#[serde(bound = "I: CoordInt", rename = "CubeCoords", try_from = "CubeCoordsRepr<I>")]
+pub struct Cube<I>
+{
+    pub q: I,
+    pub r: I,
+    pub s: I,
+}
+
+/// Unchecked representation of [`CubeCoords`], which is deserialized first and then validated when
+/// converting to [`CubeCoords`]
+#[derive(Deserialize)]
+#[serde(bound = "I: CoordInt", rename = "CubeCoords")]
+struct CubeCoordsRepr<I>
 {
-    pub q: isize,
-    pub r: isize,
-    pub s: isize,
+    q: I,
+    r: I,
+    s: I,
 }
 
-impl CubeCoords
+impl<I: CoordInt> Cube<I>
 {
     // Constants ------------------------------------------------------------ //
 
-    pub const ZERO: Self = Self{ q: 0, r: 0, s: 0 };
+    pub const ZERO: Self = Self{ q: I::ZERO, r: I::ZERO, s: I::ZERO };
 
     // Constructors --------------------------------------------------------- //
 
-    pub fn new(q: isize, r: isize, s: isize) -> Self
+    /// Creates a new set of cube coordinates
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the coordinates don't add up to `0`. Use [`CubeCoords::try_new`](crate::Cube::try_new) for coordinates
+    /// that might be invalid.
+    pub fn new(q: I, r: I, s: I) -> Self
     {
-        let coords = Self{ q, r, s };
-        if !coords.is_valid()
+        match Self::try_new(q, r, s)
         {
-            panic!("Sum of coordinates must equal 0. {}+{}+{}!=0", coords.q, coords.r, coords.s);
+            Ok(coords) => coords,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new set of cube coordinates, returning an error if the coordinates don't add up
+    /// to `0`
+    pub fn try_new(q: I, r: I, s: I) -> Result<Self, CoordsError>
+    {
+        let coords = Self{ q, r, s };
+        if coords.is_valid() {
+            Ok(coords)
+        } else {
+            Err(CoordsError::InvalidCube{ q: q.to_i128(), r: r.to_i128(), s: s.to_i128() })
         }
-        coords
     }
 
+    /// Rounds fractional coordinates to the coordinates of the tile containing them
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the coordinates can't be rounded to a valid tile. Use [`CubeCoords::try_round`](crate::Cube::try_round)
+    /// for coordinates that might not be finite or might be out of range.
     pub fn round(q: f32, r: f32, s: f32) -> Self
     {
-        let mut output = Self{ q: q.round() as isize, r: r.round() as isize, s: s.round() as isize };
+        match Self::try_round(q, r, s)
+        {
+            Ok(coords) => coords,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Rounds fractional coordinates to the coordinates of the tile containing them, returning an
+    /// error if they can't be rounded to a valid tile, or if they don't add up to `0` to within
+    /// floating point error
+    pub fn try_round(q: f32, r: f32, s: f32) -> Result<Self, CoordsError>
+    {
+        // Components that aren't finite or are too large to be corrected below without overflowing
+        // can't be rounded to a tile
+        let in_range = |value: f32| value.is_finite() && value.round().abs() < (I::MAX / I::TWO).to_f32();
+        if !(in_range(q) && in_range(r) && in_range(s)) {
+            return Err(CoordsError::Unroundable{ q, r, s });
+        }
+        // Positions that are too far off the `q + r + s == 0` plane, allowing for floating point
+        // error, aren't positions on the grid at all
+        let tolerance = 0.001 * q.abs().max(r.abs()).max(s.abs()).max(1.0);
+        if (q + r + s).abs() > tolerance {
+            return Err(CoordsError::Unroundable{ q, r, s });
+        }
+        let mut output = Self{ q: I::from_f32(q.round()), r: I::from_f32(r.round()), s: I::from_f32(s.round()) };
         // Sometimes straight rounding doesn't produce valid coordinates. Correct them if they are invalid
         if !output.is_valid() {
             // Compute difference between the rounded output of each coordinate and the original input
-            let diff_q: f32 = (q - output.q as f32).abs();
-            let diff_r: f32 = (r - output.r as f32).abs();
-            let diff_s: f32 = (s - output.s as f32).abs();
+            let diff_q: f32 = (q - output.q.to_f32()).abs();
+            let diff_r: f32 = (r - output.r.to_f32()).abs();
+            let diff_s: f32 = (s - output.s.to_f32()).abs();
             // Recompute the coordinate with the greatest difference
             if diff_q > diff_r && diff_q > diff_s {
                 output.q = -output.r - output.s;
@@ -53,16 +135,8 @@ impl CubeCoords
             } else {
                 output.s = -output.q - output.r;
             }
-            // If coordinates are still invalid, panic
-            if !output.is_valid()
-            {
-                panic!(
-                    "Unable to round fractional coordinates ({}, {}, {}) to valid cube coords. Computed output coords were {}, which are invalid",
-                    q, r, s, output
-                );
-            }
         }
-        output
+        Ok(output)
     }
 
     // Set generators ------------------------------------------------------- //
@@ -75,139 +149,196 @@ impl CubeCoords
     /// adjacent and in order between `(0, 0, 0)` and `end`.
     pub fn line_from_center(end: Self) -> Vec<Self>
     {
-        Self::line(Self::new(0, 0, 0), end)
+        Self::line(Self::ZERO, end)
     }
 
     // Static methods ------------------------------------------------------- //
 
-    pub fn distance(a: Self, b: Self) -> isize {
+    pub fn distance(a: Self, b: Self) -> I {
         let vec: Self = a - b;
-        (vec.q.abs() + vec.r.abs() + vec.s.abs()) / 2
+        (vec.q.abs() + vec.r.abs() + vec.s.abs()) / I::TWO
     }
 
     // Instance methods ----------------------------------------------------- //
 
-    pub fn is_valid(&self) -> bool
+    /// Rotates these coordinates clockwise around `(0, 0, 0)` by `steps` times 60°
+    /// 
+    /// Clockwise is with the Y-axis pointing up, the same way [`HexDirection`](crate::HexDirection)s are ordered.
+    /// 
+    /// <https://www.redblobgames.com/grids/hexagons/#rotation>
+    pub fn rotate_clockwise(self, steps: usize) -> Self
     {
-        self.q + self.r + self.s == 0
-    }
-}
-
-impl HexCoords for CubeCoords
-{
-    fn line(a: Self, b: Self) -> Vec<Self> {
-        let tiles = Self::distance(a, b)+1;
-        let mut output = Vec::default();
-        for i in 0..tiles
+        let mut output = self;
+        for _ in 0..steps % 6
         {
-            let t = i as f32 / (tiles-1) as f32;
-            output.push(a.lerp(b, t));
+            output = Self{ q: -output.s, r: -output.q, s: -output.r };
         }
         output
     }
 
-    fn ring(center: Self, radius: usize) -> Vec<Self> where Self: Sized {
-        let axial_ring = AxialCoords::ring(center.into(), radius);
-        let cube_ring = axial_ring.iter().map(|axial|{CubeCoords::from(axial)}).collect();
-        cube_ring
+    /// Rotates these coordinates counterclockwise around `(0, 0, 0)` by `steps` times 60°
+    pub fn rotate_counterclockwise(self, steps: usize) -> Self
+    {
+        self.rotate_clockwise(6 - steps % 6)
+    }
+
+    /// Rotates these coordinates clockwise around `center` by `steps` times 60°
+    pub fn rotate_clockwise_around(self, center: Self, steps: usize) -> Self
+    {
+        (self - center).rotate_clockwise(steps) + center
+    }
+
+    /// Rotates these coordinates counterclockwise around `center` by `steps` times 60°
+    pub fn rotate_counterclockwise_around(self, center: Self, steps: usize) -> Self
+    {
+        (self - center).rotate_counterclockwise(steps) + center
     }
 
-    fn area(center: Self, radius: usize) -> Vec<Self> where Self: Sized {
-        let mut output = Vec::new();
-        for i in 0..radius+1
+    /// Reflects these coordinates across the given axis through `(0, 0, 0)`
+    /// 
+    /// <https://www.redblobgames.com/grids/hexagons/#reflection>
+    pub fn reflect(self, axis: HexAxis) -> Self
+    {
+        match axis
         {
-            let mut ring = CubeCoords::ring(center, i);
-            output.append(&mut ring);
+            HexAxis::Q => Self{ q: self.q, r: self.s, s: self.r },
+            HexAxis::R => Self{ q: self.s, r: self.r, s: self.q },
+            HexAxis::S => Self{ q: self.r, r: self.q, s: self.s },
         }
-        output
     }
 
-    fn adjacent(center: Self) -> Vec<Self> {
-        vec![
-            center + cube!(0, -1, 1),
-            center + cube!(1, -1, 0),
-            center + cube!(1, 0, -1),
-            center + cube!(0, 1, -1),
-            center + cube!(-1, 1, 0),
-            center + cube!(-1, 0, 1),
-        ]
+    /// Reflects these coordinates across the given axis through `center`
+    pub fn reflect_around(self, center: Self, axis: HexAxis) -> Self
+    {
+        (self - center).reflect(axis) + center
     }
 
-    fn to_world(&self, orientation: Orientation) -> (f32, f32) {
-        AxialCoords::from(self).to_world(orientation)
+    /// Cube coordinates are only valid when `q + r + s == 0`
+    pub fn is_valid(&self) -> bool
+    {
+        // Widened so that coordinates from untrusted sources can't overflow the check
+        self.q.to_i128() + self.r.to_i128() + self.s.to_i128() == 0
     }
 
-    fn from_world(x: f32, y: f32, orientation: crate::Orientation) -> Self {
-        Self::from(AxialCoords::from_world(x, y, orientation))
+    /// Adds two sets of coordinates, returning [`None`] if any component overflows
+    pub fn checked_add(self, rhs: Self) -> Option<Self>
+    {
+        Some(Self{
+            q: self.q.checked_add(rhs.q)?,
+            r: self.r.checked_add(rhs.r)?,
+            s: self.s.checked_add(rhs.s)?,
+        })
     }
-}
 
-// TRAITS: Math Operations ---------------------------------------------------------------------- //
+    /// Subtracts two sets of coordinates, returning [`None`] if any component overflows
+    pub fn checked_sub(self, rhs: Self) -> Option<Self>
+    {
+        Some(Self{
+            q: self.q.checked_sub(rhs.q)?,
+            r: self.r.checked_sub(rhs.r)?,
+            s: self.s.checked_sub(rhs.s)?,
+        })
+    }
+
+    /// Scales a set of coordinates, returning [`None`] if any component overflows
+    pub fn checked_mul(self, rhs: I) -> Option<Self>
+    {
+        Some(Self{
+            q: self.q.checked_mul(rhs)?,
+            r: self.r.checked_mul(rhs)?,
+            s: self.s.checked_mul(rhs)?,
+        })
+    }
 
-impl Add<CubeCoords> for CubeCoords
+    /// Negates a set of coordinates, returning [`None`] if any component overflows
+    pub fn checked_neg(self) -> Option<Self>
+    {
+        Some(Self{
+            q: self.q.checked_neg()?,
+            r: self.r.checked_neg()?,
+            s: self.s.checked_neg()?,
+        })
+    }
+}
+
+impl<I: CoordInt> HexCoords for Cube<I>
 {
-    type Output = CubeCoords;
+    type Int = I;
+
+    fn to_cube(&self) -> Cube<I> {
+        *self
+    }
+
+    fn from_cube(cube: Cube<I>) -> Self {
+        cube
+    }
+
+    fn to_world(&self, orientation: Orientation) -> (f32, f32) {
+        Axial::from(self).to_world(orientation)
+    }
 
-    fn add(self, rhs: CubeCoords) -> Self::Output {
-        CubeCoords::from(AxialCoords::from(self) + AxialCoords::from(rhs))
+    fn from_world(x: f32, y: f32, orientation: crate::Orientation) -> Self {
+        Self::from(Axial::from_world(x, y, orientation))
     }
 }
 
-impl Add<&CubeCoords> for CubeCoords
+// TRAITS: Math Operations ---------------------------------------------------------------------- //
+
+impl<I: CoordInt> Add<Cube<I>> for Cube<I>
 {
-    type Output = CubeCoords;
+    type Output = Cube<I>;
 
-    fn add(self, rhs: &CubeCoords) -> Self::Output {
-        self + *rhs
+    fn add(self, rhs: Cube<I>) -> Self::Output {
+        Cube::from(Axial::from(self) + Axial::from(rhs))
     }
 }
 
-impl Add<CubeCoords> for &CubeCoords
+impl<I: CoordInt> Add<&Cube<I>> for Cube<I>
 {
-    type Output = CubeCoords;
+    type Output = Cube<I>;
 
-    fn add(self, rhs: CubeCoords) -> Self::Output {
-        CubeCoords::new(self.q + rhs.q, self.r + rhs.r, self.s + rhs.s)
+    fn add(self, rhs: &Cube<I>) -> Self::Output {
+        self + *rhs
     }
 }
 
-impl Add<&CubeCoords> for &CubeCoords
+impl<I: CoordInt> Add<Cube<I>> for &Cube<I>
 {
-    type Output = CubeCoords;
+    type Output = Cube<I>;
 
-    fn add(self, rhs: &CubeCoords) -> Self::Output {
-        CubeCoords::new(self.q + rhs.q, self.r + rhs.r, self.s + rhs.s)
+    fn add(self, rhs: Cube<I>) -> Self::Output {
+        Cube::new(self.q + rhs.q, self.r + rhs.r, self.s + rhs.s)
     }
 }
 
-impl Mul<Self> for CubeCoords
+impl<I: CoordInt> Add<&Cube<I>> for &Cube<I>
 {
-    type Output = Self;
+    type Output = Cube<I>;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self::new(self.q * rhs.q, self.r * rhs.r, self.s * rhs.s)
+    fn add(self, rhs: &Cube<I>) -> Self::Output {
+        Cube::new(self.q + rhs.q, self.r + rhs.r, self.s + rhs.s)
     }
 }
 
-impl Mul<isize> for CubeCoords
+impl<I: CoordInt> Mul<I> for Cube<I>
 {
     type Output = Self;
 
-    fn mul(self, rhs: isize) -> Self::Output {
+    fn mul(self, rhs: I) -> Self::Output {
         Self::new(self.q * rhs, self.r * rhs, self.s * rhs)
     }
 }
 
-impl Mul<usize> for CubeCoords
+impl<I: CoordInt> Mul<usize> for Cube<I>
 {
-    type Output = CubeCoords;
+    type Output = Cube<I>;
 
     fn mul(self, rhs: usize) -> Self::Output {
-        self * rhs as isize
+        self * I::from_isize(rhs as isize)
     }
 }
 
-impl Neg for CubeCoords
+impl<I: CoordInt> Neg for Cube<I>
 {
     type Output = Self;
 
@@ -216,61 +347,88 @@ impl Neg for CubeCoords
     }
 }
 
-impl Sub<Self> for CubeCoords
+impl<I: CoordInt> Sub<Self> for Cube<I>
 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        CubeCoords::from(AxialCoords::from(self) - AxialCoords::from(rhs))
+        Cube::from(Axial::from(self) - Axial::from(rhs))
     }
 }
 
 // TRAITS: Conversion --------------------------------------------------------------------------- //
 
-impl From<[f32;3]> for CubeCoords
+impl<I: CoordInt> TryFrom<[f32;3]> for Cube<I>
 {
-    fn from(value: [f32;3]) -> Self {
-        Self::new(value[0].round() as isize, value[1].round() as isize, value[2].round() as isize)
+    type Error = CoordsError;
+
+    /// Rounds the fractional coordinates to the tile containing them, see
+    /// [`CubeCoords::try_round`](crate::Cube::try_round)
+    fn try_from(value: [f32;3]) -> Result<Self, Self::Error> {
+        Self::try_round(value[0], value[1], value[2])
     }
 }
 
-impl From<AxialCoords> for CubeCoords
+impl<I: CoordInt> TryFrom<[I;3]> for Cube<I>
 {
-    /// Converts to [`CubeCoords`] from [`AxialCoords`]
+    type Error = CoordsError;
+
+    fn try_from(value: [I;3]) -> Result<Self, Self::Error> {
+        Self::try_new(value[0], value[1], value[2])
+    }
+}
+
+impl<I: CoordInt> TryFrom<(I, I, I)> for Cube<I>
+{
+    type Error = CoordsError;
+
+    fn try_from(value: (I, I, I)) -> Result<Self, Self::Error> {
+        Self::try_new(value.0, value.1, value.2)
+    }
+}
+
+impl<I: CoordInt> TryFrom<CubeCoordsRepr<I>> for Cube<I>
+{
+    type Error = CoordsError;
+
+    fn try_from(value: CubeCoordsRepr<I>) -> Result<Self, Self::Error> {
+        Self::try_new(value.q, value.r, value.s)
+    }
+}
+
+impl<I: CoordInt> From<Axial<I>> for Cube<I>
+{
+    /// Converts to [`CubeCoords`] from [`AxialCoords`](crate::AxialCoords)
     /// 
     /// <https://www.redblobgames.com/grids/hexagons/#conversions-axial>
-    fn from(value: AxialCoords) -> Self {
+    fn from(value: Axial<I>) -> Self {
         Self{ q: value.q, r: value.r, s: -value.q - value.r }
     }
 }
 
-impl From<&AxialCoords> for CubeCoords
+impl<I: CoordInt> From<&Axial<I>> for Cube<I>
 {
-    fn from(value: &AxialCoords) -> Self {
+    fn from(value: &Axial<I>) -> Self {
         Self{ q: value.q, r: value.r, s: -value.q - value.r }
     }
 }
 
 // TRAITS: Other -------------------------------------------------------------------------------- //
 
-impl Display for CubeCoords
+impl<I: CoordInt> Display for Cube<I>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {}, {})", self.q, self.r, self.s)
     }
 }
 
-impl Lerp<f32> for CubeCoords
+impl<I: CoordInt> Lerp<f32> for Cube<I>
 {
     fn lerp(self, other: Self, t: f32) -> Self {
-        let q = (self.q as f32).lerp(other.q as f32, t);
-        let r = (self.r as f32).lerp(other.r as f32, t);
-        let s: f32 = (self.s as f32).lerp(other.s as f32, t);
-        Self::round(q, r, s)
+        FractionalCube::from(self).lerp(FractionalCube::from(other), t).round()
     }
 }
 
-
 #[cfg(test)]
 mod tests
 {
@@ -453,6 +611,108 @@ mod tests
         assert_eq!(cube!(2, -1, -1), line[3]);
     }
 
+    #[test]
+    fn try_new()
+    {
+        assert_eq!(Ok(cube!(1, -2, 1)), CubeCoords::try_new(1, -2, 1));
+        assert_eq!(Err(CoordsError::InvalidCube{ q: 1, r: 1, s: 1 }), CubeCoords::try_new(1, 1, 1));
+        assert_eq!(Ok(cube!(0, 3, -3)), CubeCoords::try_from([0, 3, -3]));
+        assert_eq!(Ok(cube!(0, 3, -3)), CubeCoords::try_from((0, 3, -3)));
+        assert!(CubeCoords::try_from([0, 3, 3]).is_err());
+        assert!(CubeCoords::try_new(isize::MAX, isize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn try_round()
+    {
+        assert_eq!(Ok(cube!(1, 0, -1)), CubeCoords::try_round(0.9, 0.2, -1.1));
+        assert!(CubeCoords::try_round(f32::NAN, 0.0, 0.0).is_err());
+        assert!(CubeCoords::try_round(f32::INFINITY, f32::NEG_INFINITY, 0.0).is_err());
+        assert!(CubeCoords::try_round(1e30, -1e30, 0.0).is_err());
+        assert!(CubeCoords16::try_round(10000.0, -10000.0, 0.0).is_ok());
+        assert!(CubeCoords16::try_round(40000.0, -40000.0, 0.0).is_err());
+
+        // Rounding each component on its own gives (0, 0, -1), which has to be corrected
+        assert_eq!(Ok(cube!(0, 1, -1)), CubeCoords::try_from([0.4, 0.4, -0.8]));
+        assert!(CubeCoords::try_from([f32::NAN, 0.0, 0.0]).is_err());
+        // Positions that aren't on the grid at all
+        assert!(CubeCoords::try_from([5.0, 0.0, 0.0]).is_err());
+        assert!(CubeCoords::try_round(0.4, 0.4, 0.4).is_err());
+        assert!(CubeCoords::try_round(1000.3, -500.1, -500.2).is_ok());
+    }
+
+    #[test]
+    fn serde()
+    {
+        let coords = cube!(2, -3, 1);
+        let json = serde_json::to_string(&coords).unwrap();
+        assert_eq!(coords, serde_json::from_str::<CubeCoords>(&json).unwrap());
+        assert_eq!(cube!(1, 0, -1), serde_json::from_str::<CubeCoords>(r#"{"q":1,"r":0,"s":-1}"#).unwrap());
+        assert!(serde_json::from_str::<CubeCoords>(r#"{"q":1,"r":1,"s":1}"#).is_err());
+    }
+
+    #[test]
+    fn checked_ops()
+    {
+        assert_eq!(Some(cube!(3, -1, -2)), cube!(1, 1, -2).checked_add(cube!(2, -2, 0)));
+        assert_eq!(Some(cube!(-1, 3, -2)), cube!(1, 1, -2).checked_sub(cube!(2, -2, 0)));
+        assert_eq!(Some(cube!(3, 3, -6)), cube!(1, 1, -2).checked_mul(3));
+        assert_eq!(Some(cube!(-1, -1, 2)), cube!(1, 1, -2).checked_neg());
+
+        let far = CubeCoords::try_new(isize::MAX, isize::MIN + 1, 0).unwrap();
+        assert_eq!(None, far.checked_add(cube!(1, -1, 0)));
+        assert_eq!(None, far.checked_sub(cube!(-1, 1, 0)));
+        assert_eq!(None, far.checked_mul(2));
+        assert!(far.checked_neg().is_some());
+        assert_eq!(None, CubeCoords::try_new(isize::MIN, isize::MAX, 1).unwrap().checked_neg());
+    }
+
+    #[test]
+    fn rotate()
+    {
+        let coords = cube!(2, -3, 1);
+        assert_eq!(coords, coords.rotate_clockwise(0));
+        assert_eq!(cube!(-1, -2, 3), coords.rotate_clockwise(1));
+        assert_eq!(cube!(-3, 1, 2), coords.rotate_clockwise(2));
+        assert_eq!(-coords, coords.rotate_clockwise(3));
+        assert_eq!(coords, coords.rotate_clockwise(6));
+        assert_eq!(coords.rotate_clockwise(5), coords.rotate_counterclockwise(1));
+        assert_eq!(coords.rotate_clockwise(4), coords.rotate_counterclockwise(8));
+
+        let center = cube!(1, 1, -2);
+        assert_eq!(center, center.rotate_clockwise_around(center, 2));
+        assert_eq!(cube!(0, 1, -1), cube!(1, 0, -1).rotate_clockwise_around(center, 1));
+        assert_eq!(cube!(2, 0, -2), cube!(1, 0, -1).rotate_counterclockwise_around(center, 1));
+        for coords in CubeCoords::area(center, 3)
+        {
+            assert_eq!(coords, coords.rotate_clockwise_around(center, 4).rotate_counterclockwise_around(center, 4));
+            assert_eq!(
+                CubeCoords::distance(center, coords),
+                CubeCoords::distance(center, coords.rotate_clockwise_around(center, 1)),
+            );
+        }
+    }
+
+    #[test]
+    fn reflect()
+    {
+        let coords = cube!(2, -3, 1);
+        assert_eq!(cube!(2, 1, -3), coords.reflect(HexAxis::Q));
+        assert_eq!(cube!(1, -3, 2), coords.reflect(HexAxis::R));
+        assert_eq!(cube!(-3, 2, 1), coords.reflect(HexAxis::S));
+
+        let center = cube!(1, 1, -2);
+        assert_eq!(cube!(1, 0, -1), cube!(1, 2, -3).reflect_around(center, HexAxis::Q));
+        for axis in [HexAxis::Q, HexAxis::R, HexAxis::S]
+        {
+            assert_eq!(center, center.reflect_around(center, axis));
+            for coords in CubeCoords::area(center, 2)
+            {
+                assert_eq!(coords, coords.reflect_around(center, axis).reflect_around(center, axis));
+            }
+        }
+    }
+
     mod ops
     {
         use super::*;
@@ -0,0 +1,436 @@
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, Cube, Orientation};
+
+
+/// One of the six directions from a tile towards the tiles sharing an edge with it
+///
+/// Variants are named after the axial unit vector they correspond to, so [`HexDirection::PosQ`]
+/// is [`AxialCoords::Q`](crate::Axial::Q) and [`HexDirection::NegS`] is `-AxialCoords::S`, regardless of
+/// orientation. Use [`HexDirection::name`] to get the compass name of a direction for a given
+/// [`Orientation`].
+///
+/// Directions are ordered clockwise starting from [`HexDirection::PosQ`], with the Y-axis pointing
+/// up, the same way the corners of a tile are.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#neighbors>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum HexDirection
+{
+    PosQ,
+    NegS,
+    NegR,
+    NegQ,
+    PosS,
+    PosR,
+}
+
+impl HexDirection
+{
+    /// All directions, in clockwise order
+    pub const ALL: [Self;6] = [Self::PosQ, Self::NegS, Self::NegR, Self::NegQ, Self::PosS, Self::PosR];
+
+    /// Compass names of each direction on a pointy topped grid, in clockwise order
+    const POINTY_TOP_NAMES: [&'static str;6] = ["E", "SE", "SW", "W", "NW", "NE"];
+
+    /// Compass names of each direction on a flat topped grid, in clockwise order
+    const FLAT_TOP_NAMES: [&'static str;6] = ["NE", "SE", "S", "SW", "NW", "N"];
+
+    /// Position of this direction in [`HexDirection::ALL`]
+    pub fn index(self) -> usize
+    {
+        self as usize
+    }
+
+    /// Gets the direction at the given position in [`HexDirection::ALL`], wrapping around
+    pub fn from_index(index: usize) -> Self
+    {
+        Self::ALL[index % 6]
+    }
+
+    /// Gets the next direction clockwise from this one
+    pub fn clockwise(self) -> Self
+    {
+        self.rotate_clockwise(1)
+    }
+
+    /// Gets the next direction counterclockwise from this one
+    pub fn counterclockwise(self) -> Self
+    {
+        self.rotate_counterclockwise(1)
+    }
+
+    /// Rotates this direction clockwise by `steps` times 60°
+    pub fn rotate_clockwise(self, steps: usize) -> Self
+    {
+        Self::from_index(self.index() + steps % 6)
+    }
+
+    /// Rotates this direction counterclockwise by `steps` times 60°
+    pub fn rotate_counterclockwise(self, steps: usize) -> Self
+    {
+        Self::from_index(self.index() + 6 - steps % 6)
+    }
+
+    /// Gets the direction pointing the opposite way
+    pub fn opposite(self) -> Self
+    {
+        self.rotate_clockwise(3)
+    }
+
+    /// Gets the direction this one points to after reflecting it across the given axis. See
+    /// [`CubeCoords::reflect`](crate::Cube::reflect).
+    pub fn reflect(self, axis: HexAxis) -> Self
+    {
+        match axis
+        {
+            HexAxis::Q => Self::from_index(7 - self.index()),
+            HexAxis::R => Self::from_index(9 - self.index()),
+            HexAxis::S => Self::from_index(11 - self.index()),
+        }
+    }
+
+    /// Gets the diagonal between this direction and the next one clockwise
+    pub fn diagonal_clockwise(self) -> HexDiagonal
+    {
+        HexDiagonal::from_index(self.index())
+    }
+
+    /// Gets the diagonal between this direction and the next one counterclockwise
+    pub fn diagonal_counterclockwise(self) -> HexDiagonal
+    {
+        HexDiagonal::from_index(self.index() + 5)
+    }
+
+    /// Offset from a tile to its neighbor in this direction
+    pub fn to_axial<I: CoordInt>(self) -> Axial<I>
+    {
+        match self
+        {
+            Self::PosQ => Axial::Q,
+            Self::NegS => -Axial::S,
+            Self::NegR => -Axial::R,
+            Self::NegQ => -Axial::Q,
+            Self::PosS => Axial::S,
+            Self::PosR => Axial::R,
+        }
+    }
+
+    /// Offset from a tile to its neighbor in this direction
+    pub fn to_cube<I: CoordInt>(self) -> Cube<I>
+    {
+        Cube::from(self.to_axial())
+    }
+
+    /// Gets the direction whose offset is `offset`, if it is one of the six unit offsets
+    pub fn from_axial<I: CoordInt>(offset: Axial<I>) -> Option<Self>
+    {
+        Self::ALL.into_iter().find(|dir| dir.to_axial() == offset)
+    }
+
+    /// Gets the compass name of this direction on a grid with the given orientation, such as
+    /// `"NE"` or `"S"`
+    ///
+    /// The Y-axis is treated as pointing north.
+    pub fn name(self, orientation: Orientation) -> &'static str
+    {
+        match orientation
+        {
+            Orientation::PointyTop => Self::POINTY_TOP_NAMES[self.index()],
+            Orientation::FlatTop => Self::FLAT_TOP_NAMES[self.index()],
+        }
+    }
+
+    /// Gets the direction with the given compass name on a grid with the given orientation, if
+    /// there is one. See [`HexDirection::name`].
+    pub fn from_name(name: &str, orientation: Orientation) -> Option<Self>
+    {
+        let names = match orientation
+        {
+            Orientation::PointyTop => Self::POINTY_TOP_NAMES,
+            Orientation::FlatTop => Self::FLAT_TOP_NAMES,
+        };
+        names.iter().position(|n| n.eq_ignore_ascii_case(name)).map(Self::from_index)
+    }
+}
+
+
+/// One of the six diagonal directions from a tile, pointing through one of its corners towards
+/// the closest tile that doesn't share an edge with it
+///
+/// Variants are named after the cube component which changes by 2 along the diagonal, so
+/// [`HexDiagonal::PosQ`] is `(2, -1, -1)`. Each diagonal lies between two [`HexDirection`]s, and
+/// diagonals are ordered clockwise starting from the one between [`HexDirection::PosQ`] and
+/// [`HexDirection::NegS`].
+///
+/// <https://www.redblobgames.com/grids/hexagons/#neighbors-diagonal>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum HexDiagonal
+{
+    PosQ,
+    NegR,
+    PosS,
+    NegQ,
+    PosR,
+    NegS,
+}
+
+impl HexDiagonal
+{
+    /// All diagonals, in clockwise order
+    pub const ALL: [Self;6] = [Self::PosQ, Self::NegR, Self::PosS, Self::NegQ, Self::PosR, Self::NegS];
+
+    /// Compass names of each diagonal on a pointy topped grid, in clockwise order
+    const POINTY_TOP_NAMES: [&'static str;6] = ["ESE", "S", "WSW", "WNW", "N", "ENE"];
+
+    /// Compass names of each diagonal on a flat topped grid, in clockwise order
+    const FLAT_TOP_NAMES: [&'static str;6] = ["E", "SSE", "SSW", "W", "NNW", "NNE"];
+
+    /// Position of this diagonal in [`HexDiagonal::ALL`]
+    pub fn index(self) -> usize
+    {
+        self as usize
+    }
+
+    /// Gets the diagonal at the given position in [`HexDiagonal::ALL`], wrapping around
+    pub fn from_index(index: usize) -> Self
+    {
+        Self::ALL[index % 6]
+    }
+
+    /// Gets the next diagonal clockwise from this one
+    pub fn clockwise(self) -> Self
+    {
+        self.rotate_clockwise(1)
+    }
+
+    /// Gets the next diagonal counterclockwise from this one
+    pub fn counterclockwise(self) -> Self
+    {
+        self.rotate_counterclockwise(1)
+    }
+
+    /// Rotates this diagonal clockwise by `steps` times 60°
+    pub fn rotate_clockwise(self, steps: usize) -> Self
+    {
+        Self::from_index(self.index() + steps % 6)
+    }
+
+    /// Rotates this diagonal counterclockwise by `steps` times 60°
+    pub fn rotate_counterclockwise(self, steps: usize) -> Self
+    {
+        Self::from_index(self.index() + 6 - steps % 6)
+    }
+
+    /// Gets the diagonal pointing the opposite way
+    pub fn opposite(self) -> Self
+    {
+        self.rotate_clockwise(3)
+    }
+
+    /// Gets the two directions on either side of this diagonal, counterclockwise first
+    pub fn directions(self) -> (HexDirection, HexDirection)
+    {
+        (HexDirection::from_index(self.index()), HexDirection::from_index(self.index() + 1))
+    }
+
+    /// Offset from a tile to its diagonal neighbor in this direction
+    pub fn to_axial<I: CoordInt>(self) -> Axial<I>
+    {
+        let (a, b) = self.directions();
+        a.to_axial() + b.to_axial()
+    }
+
+    /// Offset from a tile to its diagonal neighbor in this direction
+    pub fn to_cube<I: CoordInt>(self) -> Cube<I>
+    {
+        Cube::from(self.to_axial())
+    }
+
+    /// Gets the compass name of this diagonal on a grid with the given orientation, such as
+    /// `"N"` or `"ESE"`
+    ///
+    /// The Y-axis is treated as pointing north.
+    pub fn name(self, orientation: Orientation) -> &'static str
+    {
+        match orientation
+        {
+            Orientation::PointyTop => Self::POINTY_TOP_NAMES[self.index()],
+            Orientation::FlatTop => Self::FLAT_TOP_NAMES[self.index()],
+        }
+    }
+
+    /// Gets the diagonal with the given compass name on a grid with the given orientation, if
+    /// there is one. See [`HexDiagonal::name`].
+    pub fn from_name(name: &str, orientation: Orientation) -> Option<Self>
+    {
+        let names = match orientation
+        {
+            Orientation::PointyTop => Self::POINTY_TOP_NAMES,
+            Orientation::FlatTop => Self::FLAT_TOP_NAMES,
+        };
+        names.iter().position(|n| n.eq_ignore_ascii_case(name)).map(Self::from_index)
+    }
+}
+
+
+/// One of the three axes of the hex grid, named after the cube component they keep unchanged when
+/// reflecting coordinates across them
+/// 
+/// Reflecting across [`HexAxis::Q`] keeps `q` the same and swaps `r` and `s`, so the tiles on the
+/// axis itself are the ones where `r == s`, and similarly for the other axes.
+/// 
+/// <https://www.redblobgames.com/grids/hexagons/#reflection>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum HexAxis
+{
+    Q,
+    R,
+    S,
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, cube, AxialCoords, AxialCoords16, DoubledWidthCoords, HexCoords, OddQCoords};
+
+    #[test]
+    fn rotation()
+    {
+        assert_eq!(HexDirection::NegS, HexDirection::PosQ.clockwise());
+        assert_eq!(HexDirection::PosQ, HexDirection::PosR.clockwise());
+        assert_eq!(HexDirection::PosR, HexDirection::PosQ.counterclockwise());
+        assert_eq!(HexDirection::NegQ, HexDirection::PosQ.opposite());
+        assert_eq!(HexDirection::PosS, HexDirection::NegS.opposite());
+        assert_eq!(HexDirection::NegR, HexDirection::PosQ.rotate_clockwise(14));
+        assert_eq!(HexDirection::PosS, HexDirection::PosQ.rotate_counterclockwise(8));
+        assert_eq!(HexDiagonal::NegS, HexDiagonal::PosQ.counterclockwise());
+        assert_eq!(HexDiagonal::NegQ, HexDiagonal::PosQ.opposite());
+        for dir in HexDirection::ALL
+        {
+            assert_eq!(-dir.to_cube::<isize>(), dir.opposite().to_cube());
+            assert_eq!(dir, dir.clockwise().counterclockwise());
+        }
+    }
+
+    /// Ensures that the directions are in clockwise order on the X/Y plane, starting from
+    /// [`HexDirection::PosQ`], for both orientations
+    #[test]
+    fn clockwise_in_world()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            let angle = |coords: AxialCoords| {
+                let (x, y) = coords.to_world(orientation);
+                y.atan2(x)
+            };
+            for dir in HexDirection::ALL
+            {
+                let diff = angle(dir.to_axial()) - angle(dir.clockwise().to_axial());
+                assert!((diff.rem_euclid(std::f32::consts::TAU) - std::f32::consts::FRAC_PI_3).abs() < 0.0001);
+                let diff = angle(dir.to_axial()) - angle(dir.diagonal_clockwise().to_axial());
+                assert!((diff.rem_euclid(std::f32::consts::TAU) - std::f32::consts::FRAC_PI_6).abs() < 0.0001);
+            }
+        }
+    }
+
+    #[test]
+    fn offsets()
+    {
+        assert_eq!(axial!(1, 0), HexDirection::PosQ.to_axial());
+        assert_eq!(axial!(1, -1), HexDirection::NegS.to_axial());
+        assert_eq!(cube!(0, 1, -1), HexDirection::PosR.to_cube());
+        assert_eq!(cube!(2, -1, -1), HexDiagonal::PosQ.to_cube());
+        assert_eq!(cube!(1, -2, 1), HexDiagonal::NegR.to_cube());
+        assert_eq!(cube!(1, 1, -2), HexDiagonal::NegS.to_cube());
+        assert_eq!(Some(HexDirection::NegQ), HexDirection::from_axial(axial!(-1, 0)));
+        assert_eq!(None, HexDirection::from_axial(axial!(1, 1)));
+    }
+
+    /// Ensures that neighbors match the order of [`HexCoords::adjacent`] for every coordinate type,
+    /// and that the direction to each neighbor is the direction it was found in
+    #[test]
+    fn neighbors()
+    {
+        fn check<C: HexCoords + PartialEq + std::fmt::Debug>(center: C)
+        {
+            let adjacent = C::adjacent(center);
+            for dir in HexDirection::ALL
+            {
+                let neighbor = center.neighbor(dir);
+                assert_eq!(adjacent[dir.index()], neighbor);
+                assert_eq!(Some(dir), center.direction_to(neighbor));
+                assert_eq!(Some(dir.opposite()), neighbor.direction_to(center));
+                assert_eq!(neighbor.neighbor(dir.clockwise()), center.diagonal(dir.diagonal_clockwise()));
+            }
+            assert_eq!(None, center.direction_to(center));
+        }
+        check(axial!(2, -5));
+        check(cube!(-1, 3, -2));
+        check(OddQCoords::new(3, -2));
+        check(DoubledWidthCoords::new(-1, 5));
+    }
+
+    #[test]
+    fn direction_to()
+    {
+        let center = axial!(0, 0);
+        assert_eq!(Some(HexDirection::PosQ), center.direction_to(axial!(5, 0)));
+        assert_eq!(Some(HexDirection::PosQ), center.direction_to(axial!(4, -1)));
+        assert_eq!(Some(HexDirection::NegS), center.direction_to(axial!(3, -2)));
+        assert_eq!(Some(HexDirection::NegQ), center.direction_to(axial!(-7, 2)));
+        // Exactly on a diagonal
+        assert_eq!(Some(HexDirection::NegS), center.direction_to(center.diagonal(HexDiagonal::PosQ)));
+        assert_eq!(Some(HexDirection::PosQ), center.direction_to(center.diagonal(HexDiagonal::NegS)));
+
+        // Offsets and dot products that don't fit in the component type
+        let far = AxialCoords16::new(-30000, 0);
+        assert_eq!(Some(HexDirection::PosQ), far.direction_to(AxialCoords16::new(30000, 0)));
+        assert_eq!(Some(HexDirection::NegQ), AxialCoords16::new(20000, 0).direction_to(AxialCoords16::new(-20000, 10)));
+    }
+
+    /// Ensures that rotating and reflecting directions matches rotating and reflecting their
+    /// offsets
+    #[test]
+    fn transform()
+    {
+        for dir in HexDirection::ALL
+        {
+            let offset = dir.to_cube::<isize>();
+            assert_eq!(dir.clockwise().to_cube(), offset.rotate_clockwise(1));
+            assert_eq!(dir.rotate_counterclockwise(2).to_cube(), offset.rotate_counterclockwise(2));
+            for axis in [HexAxis::Q, HexAxis::R, HexAxis::S]
+            {
+                assert_eq!(dir.reflect(axis).to_cube(), offset.reflect(axis));
+            }
+        }
+    }
+
+    #[test]
+    fn names()
+    {
+        assert_eq!("E", HexDirection::PosQ.name(Orientation::PointyTop));
+        assert_eq!("NE", HexDirection::PosQ.name(Orientation::FlatTop));
+        assert_eq!("N", HexDiagonal::PosR.name(Orientation::PointyTop));
+        assert_eq!("E", HexDiagonal::PosQ.name(Orientation::FlatTop));
+        assert_eq!(Some(HexDirection::PosR), HexDirection::from_name("ne", Orientation::PointyTop));
+        assert_eq!(Some(HexDirection::PosR), HexDirection::from_name("N", Orientation::FlatTop));
+        assert_eq!(None, HexDirection::from_name("N", Orientation::PointyTop));
+        assert_eq!(Some(HexDiagonal::NegR), HexDiagonal::from_name("S", Orientation::PointyTop));
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            for dir in HexDirection::ALL
+            {
+                assert_eq!(Some(dir), HexDirection::from_name(dir.name(orientation), orientation));
+            }
+            for diagonal in HexDiagonal::ALL
+            {
+                assert_eq!(Some(diagonal), HexDiagonal::from_name(diagonal.name(orientation), orientation));
+            }
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use std::ops::{Add, Sub};
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, CoordsError, Cube, HexCoords, Orientation};
+
+
+/// "Double-width" doubled coordinates
+///
+/// Rectangular coordinates for pointy topped grids. Every step to the side changes `col` by 2,
+/// while every step up or down changes `row` by 1 and `col` by 1. This keeps `col + row` even for
+/// every valid coordinate, and makes neighbors and distances as easy to compute as in
+/// [`AxialCoords`](crate::AxialCoords) while still mapping nicely onto rectangular maps.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#coordinates-doubled>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "I: CoordInt", try_from = "DoubledRepr<I>")]
+pub struct DoubledWidthCoords<I = isize>
+{
+    pub col: I,
+    pub row: I,
+}
+
+impl<I: CoordInt> DoubledWidthCoords<I>
+{
+    pub const ZERO: Self = Self{ col: I::ZERO, row: I::ZERO };
+
+    /// Creates a new set of doubled coordinates
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `col + row` is odd. Use [`DoubledWidthCoords::try_new`] for coordinates that might be
+    /// invalid.
+    pub fn new(col: I, row: I) -> Self
+    {
+        match Self::try_new(col, row)
+        {
+            Ok(coords) => coords,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new set of doubled coordinates, returning an error if `col + row` is odd
+    pub fn try_new(col: I, row: I) -> Result<Self, CoordsError>
+    {
+        let coords = Self{ col, row };
+        if coords.is_valid() {
+            Ok(coords)
+        } else {
+            Err(CoordsError::InvalidDoubled{ col: col.to_i128(), row: row.to_i128() })
+        }
+    }
+
+    pub fn distance(a: Self, b: Self) -> I
+    {
+        let d_col = (a.col - b.col).abs();
+        let d_row = (a.row - b.row).abs();
+        d_row + I::ZERO.max((d_col - d_row) / I::TWO)
+    }
+
+    /// Doubled coordinates are only valid when `col + row` is even
+    pub fn is_valid(&self) -> bool
+    {
+        // Widened so that coordinates from untrusted sources can't overflow the check
+        (self.col.to_i128() + self.row.to_i128()) % 2 == 0
+    }
+}
+
+impl<I: CoordInt> HexCoords for DoubledWidthCoords<I>
+{
+    type Int = I;
+
+    fn to_cube(&self) -> Cube<I> {
+        Cube::from(*self)
+    }
+
+    fn from_cube(cube: Cube<I>) -> Self {
+        Self::from(cube)
+    }
+
+    fn to_world(&self, orientation: Orientation) -> (f32, f32) {
+        Axial::from(*self).to_world(orientation)
+    }
+
+    fn from_world(x: f32, y: f32, orientation: Orientation) -> Self {
+        Self::from(Axial::from_world(x, y, orientation))
+    }
+}
+
+
+/// "Double-height" doubled coordinates
+///
+/// Rectangular coordinates for flat topped grids. Every step up or down changes `row` by 2, while
+/// every step to the side changes `col` by 1 and `row` by 1. This keeps `col + row` even for every
+/// valid coordinate, and makes neighbors and distances as easy to compute as in [`AxialCoords`](crate::AxialCoords)
+/// while still mapping nicely onto rectangular maps.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#coordinates-doubled>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "I: CoordInt", try_from = "DoubledRepr<I>")]
+pub struct DoubledHeightCoords<I = isize>
+{
+    pub col: I,
+    pub row: I,
+}
+
+impl<I: CoordInt> DoubledHeightCoords<I>
+{
+    pub const ZERO: Self = Self{ col: I::ZERO, row: I::ZERO };
+
+    /// Creates a new set of doubled coordinates
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `col + row` is odd. Use [`DoubledHeightCoords::try_new`] for coordinates that might be
+    /// invalid.
+    pub fn new(col: I, row: I) -> Self
+    {
+        match Self::try_new(col, row)
+        {
+            Ok(coords) => coords,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new set of doubled coordinates, returning an error if `col + row` is odd
+    pub fn try_new(col: I, row: I) -> Result<Self, CoordsError>
+    {
+        let coords = Self{ col, row };
+        if coords.is_valid() {
+            Ok(coords)
+        } else {
+            Err(CoordsError::InvalidDoubled{ col: col.to_i128(), row: row.to_i128() })
+        }
+    }
+
+    pub fn distance(a: Self, b: Self) -> I
+    {
+        let d_col = (a.col - b.col).abs();
+        let d_row = (a.row - b.row).abs();
+        d_col + I::ZERO.max((d_row - d_col) / I::TWO)
+    }
+
+    /// Doubled coordinates are only valid when `col + row` is even
+    pub fn is_valid(&self) -> bool
+    {
+        // Widened so that coordinates from untrusted sources can't overflow the check
+        (self.col.to_i128() + self.row.to_i128()) % 2 == 0
+    }
+}
+
+impl<I: CoordInt> HexCoords for DoubledHeightCoords<I>
+{
+    type Int = I;
+
+    fn to_cube(&self) -> Cube<I> {
+        Cube::from(*self)
+    }
+
+    fn from_cube(cube: Cube<I>) -> Self {
+        Self::from(cube)
+    }
+
+    fn to_world(&self, orientation: Orientation) -> (f32, f32) {
+        Axial::from(*self).to_world(orientation)
+    }
+
+    fn from_world(x: f32, y: f32, orientation: Orientation) -> Self {
+        Self::from(Axial::from_world(x, y, orientation))
+    }
+}
+
+/// Unchecked representation of [`DoubledWidthCoords`] and [`DoubledHeightCoords`], which is
+/// deserialized first and then validated when converting to either of them
+#[derive(Deserialize)]
+#[serde(bound = "I: CoordInt")]
+struct DoubledRepr<I>
+{
+    col: I,
+    row: I,
+}
+
+// TRAITS: MATH OPERATIONS ---------------------------------------------------------------------- //
+
+impl<I: CoordInt> Add<Self> for DoubledWidthCoords<I>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{ col: self.col + rhs.col, row: self.row + rhs.row }
+    }
+}
+
+impl<I: CoordInt> Sub<Self> for DoubledWidthCoords<I>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{ col: self.col - rhs.col, row: self.row - rhs.row }
+    }
+}
+
+impl<I: CoordInt> Add<Self> for DoubledHeightCoords<I>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{ col: self.col + rhs.col, row: self.row + rhs.row }
+    }
+}
+
+impl<I: CoordInt> Sub<Self> for DoubledHeightCoords<I>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{ col: self.col - rhs.col, row: self.row - rhs.row }
+    }
+}
+
+// TRAITS: CONVERSION --------------------------------------------------------------------------- //
+// <https://www.redblobgames.com/grids/hexagons/#conversions-doubled>
+
+impl<I: CoordInt> TryFrom<DoubledRepr<I>> for DoubledWidthCoords<I>
+{
+    type Error = CoordsError;
+
+    fn try_from(value: DoubledRepr<I>) -> Result<Self, Self::Error> {
+        Self::try_new(value.col, value.row)
+    }
+}
+
+impl<I: CoordInt> TryFrom<DoubledRepr<I>> for DoubledHeightCoords<I>
+{
+    type Error = CoordsError;
+
+    fn try_from(value: DoubledRepr<I>) -> Result<Self, Self::Error> {
+        Self::try_new(value.col, value.row)
+    }
+}
+
+impl<I: CoordInt> From<Axial<I>> for DoubledWidthCoords<I>
+{
+    fn from(value: Axial<I>) -> Self {
+        Self{ col: I::TWO * value.q + value.r, row: value.r }
+    }
+}
+
+impl<I: CoordInt> From<Cube<I>> for DoubledWidthCoords<I>
+{
+    fn from(value: Cube<I>) -> Self {
+        Self::from(Axial::from(value))
+    }
+}
+
+impl<I: CoordInt> From<DoubledWidthCoords<I>> for Axial<I>
+{
+    fn from(value: DoubledWidthCoords<I>) -> Self {
+        Self{ q: (value.col - value.row) / I::TWO, r: value.row }
+    }
+}
+
+impl<I: CoordInt> From<DoubledWidthCoords<I>> for Cube<I>
+{
+    fn from(value: DoubledWidthCoords<I>) -> Self {
+        Self::from(Axial::from(value))
+    }
+}
+
+impl<I: CoordInt> From<Axial<I>> for DoubledHeightCoords<I>
+{
+    fn from(value: Axial<I>) -> Self {
+        Self{ col: value.q, row: I::TWO * value.r + value.q }
+    }
+}
+
+impl<I: CoordInt> From<Cube<I>> for DoubledHeightCoords<I>
+{
+    fn from(value: Cube<I>) -> Self {
+        Self::from(Axial::from(value))
+    }
+}
+
+impl<I: CoordInt> From<DoubledHeightCoords<I>> for Axial<I>
+{
+    fn from(value: DoubledHeightCoords<I>) -> Self {
+        Self{ q: value.col, r: (value.row - value.col) / I::TWO }
+    }
+}
+
+impl<I: CoordInt> From<DoubledHeightCoords<I>> for Cube<I>
+{
+    fn from(value: DoubledHeightCoords<I>) -> Self {
+        Self::from(Axial::from(value))
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, cube, CubeCoords};
+
+    #[test]
+    fn from_axial()
+    {
+        assert_eq!(DoubledWidthCoords::new(0, 0), DoubledWidthCoords::from(axial!(0, 0)));
+        assert_eq!(DoubledWidthCoords::new(2, 0), DoubledWidthCoords::from(axial!(1, 0)));
+        assert_eq!(DoubledWidthCoords::new(1, 1), DoubledWidthCoords::from(axial!(0, 1)));
+        assert_eq!(DoubledWidthCoords::new(-3, -1), DoubledWidthCoords::from(axial!(-1, -1)));
+
+        assert_eq!(DoubledHeightCoords::new(0, 0), DoubledHeightCoords::from(axial!(0, 0)));
+        assert_eq!(DoubledHeightCoords::new(1, 1), DoubledHeightCoords::from(axial!(1, 0)));
+        assert_eq!(DoubledHeightCoords::new(0, 2), DoubledHeightCoords::from(axial!(0, 1)));
+        assert_eq!(DoubledHeightCoords::new(-1, -3), DoubledHeightCoords::from(axial!(-1, -1)));
+    }
+
+    /// Ensures that converting to doubled coordinates and back results in the original coordinates
+    #[test]
+    fn round_trip()
+    {
+        for coords in CubeCoords::area(cube!(-2, 3, -1), 6)
+        {
+            let doubled_width = DoubledWidthCoords::from(coords);
+            assert!(doubled_width.is_valid());
+            assert_eq!(coords, CubeCoords::from(doubled_width));
+            let doubled_height = DoubledHeightCoords::from(coords);
+            assert!(doubled_height.is_valid());
+            assert_eq!(coords, CubeCoords::from(doubled_height));
+        }
+    }
+
+    /// Ensures that distances and neighbors in doubled coordinates match the ones computed with
+    /// cube coordinates
+    #[test]
+    fn matches_cube()
+    {
+        let center = cube!(1, -2, 1);
+        for coords in CubeCoords::area(center, 4)
+        {
+            assert_eq!(
+                CubeCoords::distance(center, coords),
+                DoubledWidthCoords::distance(center.into(), coords.into()),
+            );
+            assert_eq!(
+                CubeCoords::distance(center, coords),
+                DoubledHeightCoords::distance(center.into(), coords.into()),
+            );
+        }
+        let adjacent = CubeCoords::adjacent(center);
+        for neighbor in DoubledWidthCoords::adjacent(center.into())
+        {
+            assert!(adjacent.contains(&neighbor.into()));
+        }
+        for neighbor in DoubledHeightCoords::adjacent(center.into())
+        {
+            assert!(adjacent.contains(&neighbor.into()));
+        }
+    }
+
+    #[test]
+    fn line()
+    {
+        let line = DoubledWidthCoords::line(DoubledWidthCoords::new(0, 0), DoubledWidthCoords::new(6, 0));
+        assert_eq!(4, line.len());
+        assert_eq!(DoubledWidthCoords::new(2, 0), line[1]);
+        assert_eq!(DoubledWidthCoords::new(4, 0), line[2]);
+
+        let line = DoubledHeightCoords::line(DoubledHeightCoords::new(0, 0), DoubledHeightCoords::new(0, -4));
+        assert_eq!(3, line.len());
+        assert_eq!(DoubledHeightCoords::new(0, -2), line[1]);
+    }
+
+    #[test]
+    fn world_round_trip()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            for coords in DoubledHeightCoords::area(DoubledHeightCoords::<isize>::ZERO, 3)
+            {
+                let (x, y) = coords.to_world(orientation);
+                assert_eq!(coords, DoubledHeightCoords::from_world(x, y, orientation));
+            }
+            for coords in DoubledWidthCoords::ring(DoubledWidthCoords::new(3, 1), 2)
+            {
+                let (x, y) = coords.to_world(orientation);
+                assert_eq!(coords, DoubledWidthCoords::from_world(x, y, orientation));
+            }
+        }
+    }
+
+    /// Ensures that coordinates with an odd `col + row`, which would convert to the same tile as
+    /// another set of coordinates, are rejected
+    #[test]
+    fn invalid()
+    {
+        assert_eq!(Err(CoordsError::InvalidDoubled{ col: 1, row: 0 }), DoubledWidthCoords::try_new(1, 0));
+        assert_eq!(Err(CoordsError::InvalidDoubled{ col: -2, row: 3 }), DoubledHeightCoords::try_new(-2, 3));
+        assert_eq!(Ok(DoubledWidthCoords::new(3, 1)), DoubledWidthCoords::try_new(3, 1));
+
+        let json = serde_json::to_string(&DoubledWidthCoords::new(-3, 1)).unwrap();
+        assert_eq!(DoubledWidthCoords::new(-3, 1), serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<DoubledWidthCoords>(r#"{"col":2,"row":1}"#).is_err());
+        assert!(serde_json::from_str::<DoubledHeightCoords>(r#"{"col":0,"row":-1}"#).is_err());
+
+        // Components at the limits of the integer type
+        let coords = serde_json::from_str::<DoubledWidthCoords<i8>>(r#"{"col":127,"row":1}"#).unwrap();
+        assert_eq!(DoubledWidthCoords::<i8>::new(127, 1), coords);
+        assert!(serde_json::from_str::<DoubledWidthCoords<i8>>(r#"{"col":127,"row":-128}"#).is_err());
+        assert!(serde_json::from_str::<DoubledHeightCoords<i8>>(r#"{"col":-128,"row":-128}"#).is_ok());
+        assert!(serde_json::from_str::<DoubledHeightCoords<i8>>(r#"{"col":127,"row":127}"#).is_ok());
+    }
+}
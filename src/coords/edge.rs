@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, HexCoords, HexDirection, HexVertex, Layout, Orientation};
+
+
+/// Coordinates of an edge between two tiles, for things like walls and rivers
+///
+/// Every edge is shared by two tiles, so it could be described from either of them. Edges are
+/// always stored in a canonical form, using the tile for which the edge lies in
+/// [`HexDirection::PosQ`], [`HexDirection::NegS`] or [`HexDirection::NegR`], so that equal edges
+/// compare and hash equally no matter which tile they were created from.
+///
+/// The component type `I` defaults to `isize`, but can be any [`CoordInt`].
+///
+/// <https://www.redblobgames.com/grids/parts/#hexagon-coordinates>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "I: CoordInt", from = "HexEdgeRepr<I>")]
+pub struct HexEdge<I = isize>
+{
+    tile: Axial<I>,
+    direction: HexDirection,
+}
+
+/// Representation of [`HexEdge`] that might not be in canonical form, which is deserialized first
+/// and then converted to canonical form
+#[derive(Deserialize)]
+#[serde(bound = "I: CoordInt", rename = "HexEdge")]
+struct HexEdgeRepr<I>
+{
+    tile: Axial<I>,
+    direction: HexDirection,
+}
+
+impl<I: CoordInt> HexEdge<I>
+{
+    /// Gets the edge of `tile` in the given direction
+    pub fn new<C: HexCoords<Int = I>>(tile: C, direction: HexDirection) -> Self
+    {
+        let tile = Axial::from(tile.to_cube());
+        if direction.index() < 3 {
+            Self{ tile, direction }
+        } else {
+            Self{ tile: tile + direction.to_axial(), direction: direction.opposite() }
+        }
+    }
+
+    /// Gets all six edges of `tile`, in the same order as [`HexDirection::ALL`]
+    pub fn of_tile<C: HexCoords<Int = I>>(tile: C) -> [Self;6]
+    {
+        HexDirection::ALL.map(|dir| Self::new(tile, dir))
+    }
+
+    /// Tile this edge is stored relative to in canonical form
+    pub fn tile(&self) -> Axial<I>
+    {
+        self.tile
+    }
+
+    /// Direction of this edge from [`HexEdge::tile`], which is always [`HexDirection::PosQ`],
+    /// [`HexDirection::NegS`] or [`HexDirection::NegR`]
+    pub fn direction(&self) -> HexDirection
+    {
+        self.direction
+    }
+
+    /// Gets the two tiles on either side of this edge
+    pub fn tiles(&self) -> [Axial<I>;2]
+    {
+        [self.tile, self.tile + self.direction.to_axial()]
+    }
+
+    /// Gets the two vertices at the ends of this edge
+    pub fn vertices(&self) -> [HexVertex<I>;2]
+    {
+        let corner = self.direction.index() + 1;
+        [HexVertex::new(self.tile, corner), HexVertex::new(self.tile, corner + 1)]
+    }
+
+    /// Gets the four edges that share a vertex with this edge
+    pub fn neighbors(&self) -> [Self;4]
+    {
+        let [a, b] = self.vertices().map(|vertex| {
+            let mut others = vertex.edges().into_iter().filter(|edge| edge != self);
+            [others.next().unwrap(), others.next().unwrap()]
+        });
+        [a[0], a[1], b[0], b[1]]
+    }
+
+    /// Gets the positions of the two ends of this edge on the X/Y plane
+    pub fn endpoints(&self, orientation: Orientation) -> [(f32, f32);2]
+    {
+        self.vertices().map(|vertex| vertex.to_world(orientation))
+    }
+
+    /// Gets the positions of the two ends of this edge on the X/Y plane in the given [`Layout`]
+    pub fn endpoints_in(&self, layout: Layout) -> [(f32, f32);2]
+    {
+        self.vertices().map(|vertex| vertex.to_world_in(layout))
+    }
+
+    /// Gets the position of the middle of this edge on the X/Y plane
+    pub fn to_world(&self, orientation: Orientation) -> (f32, f32)
+    {
+        let [a, b] = self.endpoints(orientation);
+        ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+    }
+
+    /// Gets the position of the middle of this edge on the X/Y plane in the given [`Layout`]
+    pub fn to_world_in(&self, layout: Layout) -> (f32, f32)
+    {
+        let [a, b] = self.endpoints_in(layout);
+        ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+    }
+}
+
+impl<I: CoordInt> From<HexEdgeRepr<I>> for HexEdge<I>
+{
+    fn from(value: HexEdgeRepr<I>) -> Self {
+        Self::new(value.tile, value.direction)
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, OddRCoords};
+
+    /// Ensures that an edge is the same no matter which of its tiles it was created from
+    #[test]
+    fn canonical()
+    {
+        let tile = axial!(2, -1);
+        for dir in HexDirection::ALL
+        {
+            let edge = HexEdge::new(tile, dir);
+            assert_eq!(edge, HexEdge::new(tile.neighbor(dir), dir.opposite()));
+            assert!(edge.direction().index() < 3);
+            let tiles = edge.tiles();
+            assert!(tiles.contains(&tile));
+            assert!(tiles.contains(&tile.neighbor(dir)));
+        }
+        assert_eq!(HexEdge::new(axial!(1, -1), HexDirection::PosQ), HexEdge::new(axial!(2, -1), HexDirection::NegQ));
+        assert_eq!(HexEdge::new(OddRCoords::new(0, 0), HexDirection::PosQ), HexEdge::new(axial!(0, 0), HexDirection::PosQ));
+    }
+
+    #[test]
+    fn neighbors()
+    {
+        let edge = HexEdge::new(axial!(0, 0), HexDirection::PosQ);
+        let neighbors = edge.neighbors();
+        assert!(!neighbors.contains(&edge));
+        // Two of the neighbors belong to each of the tiles next to the edge, and they all belong
+        // to one of the two tiles at the ends of the edge
+        for tile in edge.tiles()
+        {
+            let edges = HexEdge::of_tile(tile);
+            assert_eq!(2, neighbors.iter().filter(|neighbor| edges.contains(neighbor)).count());
+        }
+        for neighbor in neighbors
+        {
+            assert!(neighbor.neighbors().contains(&edge));
+        }
+    }
+
+    /// Ensures that the edges of neighboring tiles are in the same place on the X/Y plane
+    #[test]
+    fn to_world()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            let tile = axial!(-1, 3);
+            let (tile_x, tile_y) = tile.to_world(orientation);
+            for dir in HexDirection::ALL
+            {
+                let edge = HexEdge::new(tile, dir);
+                let (x, y) = edge.to_world(orientation);
+                let (neighbor_x, neighbor_y) = tile.neighbor(dir).to_world(orientation);
+                assert!((x - (tile_x + neighbor_x) / 2.0).abs() < 0.0001);
+                assert!((y - (tile_y + neighbor_y) / 2.0).abs() < 0.0001);
+                let corners = tile.corners(orientation);
+                for (x, y) in edge.endpoints(orientation)
+                {
+                    assert!(corners.iter().any(|corner| (corner.0 - x).abs() < 0.0001 && (corner.1 - y).abs() < 0.0001));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn serde()
+    {
+        let edge = HexEdge::new(axial!(3, 1), HexDirection::PosS);
+        let json = serde_json::to_string(&edge).unwrap();
+        assert_eq!(edge, serde_json::from_str(&json).unwrap());
+        // Edges that aren't in canonical form are converted when deserializing
+        let json = r#"{"tile":{"q":0,"r":0},"direction":"NegQ"}"#;
+        assert_eq!(HexEdge::new(axial!(0, 0), HexDirection::NegQ), serde_json::from_str(json).unwrap());
+    }
+}
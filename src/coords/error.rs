@@ -0,0 +1,31 @@
+use std::{error::Error, fmt::Display};
+
+
+/// Error returned when coordinates can't be constructed or computed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoordsError
+{
+    /// The components of a set of cube coordinates don't add up to `0`
+    InvalidCube{ q: i128, r: i128, s: i128 },
+
+    /// The components of a set of doubled coordinates add up to an odd number
+    InvalidDoubled{ col: i128, row: i128 },
+
+    /// A set of fractional coordinates couldn't be rounded to a valid tile, either because one of
+    /// the components isn't finite or because the tile is outside the range of the coordinate type
+    Unroundable{ q: f32, r: f32, s: f32 },
+}
+
+impl Display for CoordsError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self
+        {
+            Self::InvalidCube{ q, r, s } => write!(f, "Sum of coordinates must equal 0. {}+{}+{}!=0", q, r, s),
+            Self::InvalidDoubled{ col, row } => write!(f, "Sum of doubled coordinates must be even. {}+{} is odd", col, row),
+            Self::Unroundable{ q, r, s } => write!(f, "Unable to round fractional coordinates ({}, {}, {}) to valid cube coords", q, r, s),
+        }
+    }
+}
+
+impl Error for CoordsError {}
@@ -0,0 +1,353 @@
+use std::ops::{Add, Mul, Neg, Sub};
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, Cube, Layout, Orientation};
+
+
+/// Fractional cube coordinates
+///
+/// Represents a position anywhere inside the hex grid rather than just the center of a tile, for
+/// things like smooth movement between tiles or continuous raycasts. Use [`FractionalCube::round`]
+/// to get the tile containing the position. Implements [`Lerp`](lerp::Lerp) through its
+/// arithmetic operators.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#rounding>
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct FractionalCube
+{
+    pub q: f32,
+    pub r: f32,
+    pub s: f32,
+}
+
+impl FractionalCube
+{
+    pub const ZERO: Self = Self{ q: 0.0, r: 0.0, s: 0.0 };
+
+    pub fn new(q: f32, r: f32, s: f32) -> Self
+    {
+        Self{ q, r, s }
+    }
+
+    /// Distance between two positions, measured in tiles
+    pub fn distance(a: Self, b: Self) -> f32
+    {
+        let vec = a - b;
+        (vec.q.abs() + vec.r.abs() + vec.s.abs()) / 2.0
+    }
+
+    /// Gets the position in hex space of the given position on the X/Y plane
+    pub fn from_world(x: f32, y: f32, orientation: Orientation) -> Self
+    {
+        Self::from(FractionalAxial::from_world(x, y, orientation))
+    }
+
+    /// Gets the position in hex space of the given position on the X/Y plane in the given [`Layout`]
+    pub fn from_world_in(x: f32, y: f32, layout: Layout) -> Self
+    {
+        Self::from(FractionalAxial::from_world_in(x, y, layout))
+    }
+
+    /// Gets the position of this point on the X/Y plane
+    pub fn to_world(&self, orientation: Orientation) -> (f32, f32)
+    {
+        FractionalAxial::from(*self).to_world(orientation)
+    }
+
+    /// Gets the position of this point on the X/Y plane in the given [`Layout`]
+    pub fn to_world_in(&self, layout: Layout) -> (f32, f32)
+    {
+        FractionalAxial::from(*self).to_world_in(layout)
+    }
+
+    /// Gets the coordinates of the tile containing this position
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the position can't be rounded to a valid tile, see [`CubeCoords::try_round`](crate::Cube::try_round).
+    pub fn round<I: CoordInt>(&self) -> Cube<I>
+    {
+        Cube::round(self.q, self.r, self.s)
+    }
+}
+
+
+/// Fractional axial coordinates
+///
+/// Same as [`FractionalCube`], without the redundant third coordinate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct FractionalAxial
+{
+    pub q: f32,
+    pub r: f32,
+}
+
+impl FractionalAxial
+{
+    pub const ZERO: Self = Self{ q: 0.0, r: 0.0 };
+
+    pub fn new(q: f32, r: f32) -> Self
+    {
+        Self{ q, r }
+    }
+
+    /// Distance between two positions, measured in tiles
+    pub fn distance(a: Self, b: Self) -> f32
+    {
+        FractionalCube::distance(a.into(), b.into())
+    }
+
+    /// Gets the position in hex space of the given position on the X/Y plane
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#pixel-to-hex>
+    pub fn from_world(x: f32, y: f32, orientation: Orientation) -> Self
+    {
+        let sqrt_3 = 3.0f32.sqrt();
+        match orientation
+        {
+            Orientation::PointyTop => Self{
+                q: sqrt_3 / 3.0 * x - 1.0 / 3.0 * y,
+                r: 2.0 / 3.0 * y,
+            },
+            Orientation::FlatTop => Self{
+                q: 2.0 / 3.0 * x,
+                r: -1.0 / 3.0 * x + sqrt_3 / 3.0 * y,
+            },
+        }
+    }
+
+    /// Gets the position in hex space of the given position on the X/Y plane in the given [`Layout`]
+    pub fn from_world_in(x: f32, y: f32, layout: Layout) -> Self
+    {
+        let (x, y) = layout.inverse_transform(x, y);
+        Self::from_world(x, y, layout.orientation)
+    }
+
+    /// Gets the position of this point on the X/Y plane
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#hex-to-pixel>
+    pub fn to_world(&self, orientation: Orientation) -> (f32, f32)
+    {
+        match orientation
+        {
+            Orientation::FlatTop => {
+                let x = self.q * orientation.tile_spacing_x();
+                let y = self.r * orientation.tile_height() + self.q * orientation.tile_height() / 2.0;
+                (x, y)
+            },
+            Orientation::PointyTop => {
+                let x = self.q * orientation.tile_width() + self.r * orientation.tile_width() / 2.0;
+                let y = self.r * orientation.tile_spacing_y();
+                (x, y)
+            },
+        }
+    }
+
+    /// Gets the position of this point on the X/Y plane in the given [`Layout`]
+    pub fn to_world_in(&self, layout: Layout) -> (f32, f32)
+    {
+        let (x, y) = self.to_world(layout.orientation);
+        layout.transform(x, y)
+    }
+
+    /// Gets the coordinates of the tile containing this position
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the position can't be rounded to a valid tile, see [`CubeCoords::try_round`](crate::Cube::try_round).
+    pub fn round<I: CoordInt>(&self) -> Axial<I>
+    {
+        Axial::from(FractionalCube::from(*self).round())
+    }
+}
+
+// TRAITS: MATH OPERATIONS ---------------------------------------------------------------------- //
+
+impl Add<Self> for FractionalCube
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{ q: self.q + rhs.q, r: self.r + rhs.r, s: self.s + rhs.s }
+    }
+}
+
+impl Sub<Self> for FractionalCube
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{ q: self.q - rhs.q, r: self.r - rhs.r, s: self.s - rhs.s }
+    }
+}
+
+impl Mul<f32> for FractionalCube
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self{ q: self.q * rhs, r: self.r * rhs, s: self.s * rhs }
+    }
+}
+
+impl Neg for FractionalCube
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self{ q: -self.q, r: -self.r, s: -self.s }
+    }
+}
+
+impl Add<Self> for FractionalAxial
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{ q: self.q + rhs.q, r: self.r + rhs.r }
+    }
+}
+
+impl Sub<Self> for FractionalAxial
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{ q: self.q - rhs.q, r: self.r - rhs.r }
+    }
+}
+
+impl Mul<f32> for FractionalAxial
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self{ q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl Neg for FractionalAxial
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self{ q: -self.q, r: -self.r }
+    }
+}
+
+// TRAITS: CONVERSION --------------------------------------------------------------------------- //
+
+impl<I: CoordInt> From<Cube<I>> for FractionalCube
+{
+    fn from(value: Cube<I>) -> Self {
+        Self{ q: value.q.to_f32(), r: value.r.to_f32(), s: value.s.to_f32() }
+    }
+}
+
+impl<I: CoordInt> From<Axial<I>> for FractionalCube
+{
+    fn from(value: Axial<I>) -> Self {
+        Self::from(Cube::from(value))
+    }
+}
+
+impl From<FractionalAxial> for FractionalCube
+{
+    fn from(value: FractionalAxial) -> Self {
+        Self{ q: value.q, r: value.r, s: -value.q - value.r }
+    }
+}
+
+impl<I: CoordInt> From<Axial<I>> for FractionalAxial
+{
+    fn from(value: Axial<I>) -> Self {
+        Self{ q: value.q.to_f32(), r: value.r.to_f32() }
+    }
+}
+
+impl<I: CoordInt> From<Cube<I>> for FractionalAxial
+{
+    fn from(value: Cube<I>) -> Self {
+        Self::from(Axial::from(value))
+    }
+}
+
+impl From<FractionalCube> for FractionalAxial
+{
+    fn from(value: FractionalCube) -> Self {
+        Self{ q: value.q, r: value.r }
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use lerp::Lerp;
+    use crate::{axial, cube, CubeCoords, HexCoords};
+
+    #[test]
+    fn distance()
+    {
+        assert_eq!(0.0, FractionalCube::distance(FractionalCube::ZERO, FractionalCube::ZERO));
+        assert_eq!(1.0, FractionalCube::distance(FractionalCube::ZERO, cube!(1, -1, 0).into()));
+        assert_eq!(0.5, FractionalCube::distance(FractionalCube::ZERO, FractionalCube::new(0.5, -0.5, 0.0)));
+        assert_eq!(1.5, FractionalAxial::distance(FractionalAxial::new(-0.5, 0.0), FractionalAxial::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn lerp()
+    {
+        let start = FractionalCube::from(cube!(0, 0, 0));
+        let end = FractionalCube::from(cube!(2, -2, 0));
+        assert_eq!(FractionalCube::new(1.0, -1.0, 0.0), start.lerp(end, 0.5));
+        assert_eq!(cube!(1, -1, 0), start.lerp(end, 0.6).round());
+        assert_eq!(cube!(2, -2, 0), start.lerp(end, 0.9).round());
+        let start = FractionalAxial::from(axial!(0, 0));
+        let end = FractionalAxial::from(axial!(0, 4));
+        assert_eq!(FractionalAxial::new(0.0, 1.0), start.lerp(end, 0.25));
+    }
+
+    #[test]
+    fn ops()
+    {
+        let a = FractionalCube::new(0.5, -1.0, 0.5);
+        let b = FractionalCube::new(1.0, 0.0, -1.0);
+        assert_eq!(FractionalCube::new(1.5, -1.0, -0.5), a + b);
+        assert_eq!(FractionalCube::new(-0.5, -1.0, 1.5), a - b);
+        assert_eq!(FractionalCube::new(1.0, -2.0, 1.0), a * 2.0);
+        assert_eq!(FractionalCube::new(-0.5, 1.0, -0.5), -a);
+    }
+
+    #[test]
+    fn round()
+    {
+        assert_eq!(cube!(0, 0, 0), FractionalCube::new(0.1, 0.2, -0.3).round());
+        assert_eq!(cube!(1, 0, -1), FractionalCube::new(0.9, 0.2, -1.1).round());
+        assert_eq!(axial!(1, -1), FractionalAxial::new(0.8, -0.7).round());
+    }
+
+    /// Ensures that positions in world space convert to positions in hex space and back without
+    /// changing, and that the center of each tile matches the tile's own world position
+    #[test]
+    fn world_round_trip()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            for (x, y) in [(0.0, 0.0), (0.3, -1.7), (12.5, 4.25), (-3.0, 8.0)]
+            {
+                let (new_x, new_y) = FractionalCube::from_world(x, y, orientation).to_world(orientation);
+                assert!((x - new_x).abs() < 0.0001 && (y - new_y).abs() < 0.0001);
+            }
+            for coords in CubeCoords::area(CubeCoords::ZERO, 3)
+            {
+                let (x, y) = coords.to_world(orientation);
+                let fractional = FractionalCube::from_world(x, y, orientation);
+                assert!(FractionalCube::distance(coords.into(), fractional) < 0.0001);
+                assert_eq!(coords, fractional.round());
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, BitAnd, Div, Mul, Neg, Rem, Sub},
+};
+use serde::{Deserialize, Serialize};
+
+
+/// Integer type that can be used for the components of the coordinate types.
+///
+/// Implemented for `i8`, `i16`, `i32`, `i64` and `isize`. Smaller types save memory on large maps,
+/// while fixed size types keep the serialized format of the coordinates the same across platforms.
+pub trait CoordInt
+where Self: Copy + Debug + Default + Display + Eq + Hash + Ord + Send + Sync + 'static,
+    Self: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>,
+    Self: Rem<Output = Self> + Neg<Output = Self> + BitAnd<Output = Self>,
+    Self: Serialize + for<'de> Deserialize<'de>,
+{
+    const ZERO: Self;
+
+    const ONE: Self;
+
+    const TWO: Self;
+
+    const NEG_ONE: Self;
+
+    const MIN: Self;
+
+    const MAX: Self;
+
+    /// Converts an `isize` to this type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is out of range for this type
+    fn from_isize(value: isize) -> Self;
+
+    /// Converts this value to an `isize`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is out of range for an `isize`, which can only happen with `i64` on
+    /// platforms with 32-bit pointers.
+    fn to_isize(self) -> isize;
+
+    /// Losslessly widens this value to an `i128`, for checks that must not overflow
+    fn to_i128(self) -> i128;
+
+    /// Narrows an `i128` back to this type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is out of range for this type
+    fn from_i128(value: i128) -> Self;
+
+    /// Converts an `f32` to this type, truncating any fractional part and saturating at the limits
+    /// of this type
+    fn from_f32(value: f32) -> Self;
+
+    fn to_f32(self) -> f32;
+
+    fn abs(self) -> Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Implements [`CoordInt`] for primitive integer types
+macro_rules! impl_coord_int {
+    ($($int:ty),*) => {$(
+        impl CoordInt for $int
+        {
+            const ZERO: Self = 0;
+
+            const ONE: Self = 1;
+
+            const TWO: Self = 2;
+
+            const NEG_ONE: Self = -1;
+
+            const MIN: Self = <$int>::MIN;
+
+            const MAX: Self = <$int>::MAX;
+
+            fn from_isize(value: isize) -> Self {
+                match Self::try_from(value) {
+                    Ok(value) => value,
+                    Err(_) => panic!("{} is out of range for {}", value, stringify!($int)),
+                }
+            }
+
+            fn to_isize(self) -> isize {
+                match isize::try_from(self) {
+                    Ok(value) => value,
+                    Err(_) => panic!("{} is out of range for isize", self),
+                }
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                match Self::try_from(value) {
+                    Ok(value) => value,
+                    Err(_) => panic!("{} is out of range for {}", value, stringify!($int)),
+                }
+            }
+
+            fn from_f32(value: f32) -> Self {
+                value as Self
+            }
+
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn abs(self) -> Self {
+                <$int>::abs(self)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$int>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$int>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$int>::checked_mul(self, rhs)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$int>::checked_neg(self)
+            }
+        }
+    )*};
+}
+
+impl_coord_int!(i8, i16, i32, i64, isize);
@@ -0,0 +1,601 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+use lerp::Lerp;
+use crate::{Axial, CoordInt, FractionalCube, HexCoords, HexDirection, Orientation};
+
+
+/// Iterator over the tiles in a ring around a center tile, created by [`HexCoords::ring_iter`]
+///
+/// Starts at the tile `radius` steps away from the center in [`HexDirection::PosQ`], and walks
+/// around the ring clockwise.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#rings-single>
+#[derive(Clone, Debug)]
+pub struct RingIter<C: HexCoords>
+{
+    current: Axial<C::Int>,
+    radius: usize,
+    index: usize,
+    len: usize,
+    _coords: PhantomData<C>,
+}
+
+impl<C: HexCoords> RingIter<C>
+{
+    pub(crate) fn new(center: C, radius: usize) -> Self
+    {
+        let center = Axial::from(center.to_cube());
+        Self{
+            current: center + HexDirection::PosQ.to_axial() * radius,
+            radius,
+            index: 0,
+            len: ring_len(radius),
+            _coords: PhantomData,
+        }
+    }
+}
+
+impl<C: HexCoords> Iterator for RingIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let output = self.current;
+        // Each side of the ring points two directions clockwise from the corner it starts at
+        if let Some(side) = self.index.checked_div(self.radius) {
+            self.current = self.current + HexDirection::from_index(side + 2).to_axial();
+        }
+        self.index += 1;
+        Some(C::from_cube(output.into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for RingIter<C> {}
+
+impl<C: HexCoords> FusedIterator for RingIter<C> {}
+
+
+/// Iterator over the tiles in a hexagonal area, ring by ring from the center outwards, created by
+/// [`HexCoords::spiral_iter`]
+///
+/// <https://www.redblobgames.com/grids/hexagons/#rings-spiral>
+#[derive(Clone, Debug)]
+pub struct SpiralIter<C: HexCoords>
+{
+    center: C,
+    radius: usize,
+    ring: RingIter<C>,
+}
+
+impl<C: HexCoords> SpiralIter<C>
+{
+    pub(crate) fn new(center: C, radius: usize) -> Self
+    {
+        Self{ center, radius, ring: RingIter::new(center, 0) }
+    }
+}
+
+impl<C: HexCoords> Iterator for SpiralIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(coords) = self.ring.next() {
+            return Some(coords);
+        }
+        if self.ring.radius >= self.radius {
+            return None;
+        }
+        self.ring = RingIter::new(self.center, self.ring.radius + 1);
+        self.ring.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.ring.len() + area_len(self.radius) - area_len(self.ring.radius);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for SpiralIter<C> {}
+
+impl<C: HexCoords> FusedIterator for SpiralIter<C> {}
+
+
+/// Iterator over the tiles in a hexagonal area, column by column, created by
+/// [`HexCoords::area_iter`]
+///
+/// Cheaper than [`SpiralIter`] when the order of the tiles doesn't matter.
+///
+/// <https://www.redblobgames.com/grids/hexagons/#range-coordinate>
+#[derive(Clone, Debug)]
+pub struct AreaIter<C: HexCoords>
+{
+    center: Axial<C::Int>,
+    radius: C::Int,
+    q: C::Int,
+    r: C::Int,
+    remaining: usize,
+    _coords: PhantomData<C>,
+}
+
+impl<C: HexCoords> AreaIter<C>
+{
+    pub(crate) fn new(center: C, radius: usize) -> Self
+    {
+        let n = C::Int::from_isize(radius as isize);
+        // Each column starts at `r == max(-n, -q - n)`, which is `0` for the first one
+        Self{
+            center: Axial::from(center.to_cube()),
+            radius: n,
+            q: -n,
+            r: C::Int::ZERO,
+            remaining: area_len(radius),
+            _coords: PhantomData,
+        }
+    }
+}
+
+impl<C: HexCoords> Iterator for AreaIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let n = self.radius;
+        let output = self.center + Axial::new(self.q, self.r);
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            if self.r < n.min(n - self.q) {
+                self.r = self.r + C::Int::ONE;
+            } else {
+                self.q = self.q + C::Int::ONE;
+                self.r = (-n).max(-self.q - n);
+            }
+        }
+        Some(C::from_cube(output.into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for AreaIter<C> {}
+
+impl<C: HexCoords> FusedIterator for AreaIter<C> {}
+
+
+/// Iterator over the tiles in a line between two tiles, created by [`HexCoords::line_iter`]
+///
+/// <https://www.redblobgames.com/grids/hexagons/#line-drawing>
+#[derive(Clone, Debug)]
+pub struct LineIter<C: HexCoords>
+{
+    a: FractionalCube,
+    b: FractionalCube,
+    index: usize,
+    len: usize,
+    _coords: PhantomData<C>,
+}
+
+impl<C: HexCoords> LineIter<C>
+{
+    pub(crate) fn new(a: C, b: C) -> Self
+    {
+        let (a, b) = (a.to_cube(), b.to_cube());
+        let distance = crate::Cube::distance(a, b).to_isize() as usize;
+        Self{
+            a: FractionalCube::from(a),
+            b: FractionalCube::from(b),
+            index: 0,
+            len: distance + 1,
+            _coords: PhantomData,
+        }
+    }
+}
+
+impl<C: HexCoords> Iterator for LineIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        // A line from a tile to itself is just that tile, avoid dividing by zero
+        let t = if self.len > 1 { self.index as f32 / (self.len - 1) as f32 } else { 0.0 };
+        self.index += 1;
+        Some(C::from_cube(self.a.lerp(self.b, t).round()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for LineIter<C> {}
+
+impl<C: HexCoords> FusedIterator for LineIter<C> {}
+
+
+/// Iterator over the six tiles adjacent to a tile, in the same order as [`HexDirection::ALL`],
+/// created by [`HexCoords::neighbors_iter`]
+#[derive(Clone, Debug)]
+pub struct NeighborsIter<C: HexCoords>
+{
+    center: Axial<C::Int>,
+    directions: std::array::IntoIter<HexDirection, 6>,
+    _coords: PhantomData<C>,
+}
+
+impl<C: HexCoords> NeighborsIter<C>
+{
+    pub(crate) fn new(center: C) -> Self
+    {
+        Self{
+            center: Axial::from(center.to_cube()),
+            directions: HexDirection::ALL.into_iter(),
+            _coords: PhantomData,
+        }
+    }
+}
+
+impl<C: HexCoords> Iterator for NeighborsIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.directions.next()?;
+        Some(C::from_cube((self.center + dir.to_axial()).into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.directions.size_hint()
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for NeighborsIter<C> {}
+
+impl<C: HexCoords> FusedIterator for NeighborsIter<C> {}
+
+
+/// Iterator over the tiles in a rectangle, parallelogram or triangle, created by
+/// [`HexCoords::rect_iter`], [`HexCoords::parallelogram_iter`] or [`HexCoords::triangle_iter`]
+///
+/// Tiles are generated one row at a time, starting from the row containing the corner tile.
+///
+/// <https://www.redblobgames.com/grids/hexagons/implementation.html#map-shapes>
+#[derive(Clone, Debug)]
+pub struct ShapeIter<C: HexCoords>
+{
+    corner: Axial<C::Int>,
+    shape: Shape,
+    row: usize,
+    col: usize,
+    remaining: usize,
+    _coords: PhantomData<C>,
+}
+
+/// Shapes generated by [`ShapeIter`]
+#[derive(Clone, Copy, Debug)]
+enum Shape
+{
+    Rect{ width: usize, height: usize, orientation: Orientation },
+    Parallelogram{ width: usize, height: usize },
+    Triangle{ size: usize },
+}
+
+impl Shape
+{
+    fn row_len(&self, row: usize) -> usize
+    {
+        match *self
+        {
+            Self::Rect{ width, orientation: Orientation::PointyTop, .. } => width,
+            Self::Rect{ height, orientation: Orientation::FlatTop, .. } => height,
+            Self::Parallelogram{ width, .. } => width,
+            Self::Triangle{ size } => size - row,
+        }
+    }
+
+    /// Offset from the corner of the shape of the tile in the given row and column
+    fn offset(&self, row: usize, col: usize) -> (isize, isize)
+    {
+        let (row, col) = (row as isize, col as isize);
+        match *self
+        {
+            // Every other row is shifted by half a tile, so shift every second row back by a
+            // whole tile to keep the sides of the rectangle straight
+            Self::Rect{ orientation: Orientation::PointyTop, .. } => (col - row / 2, row),
+            Self::Rect{ orientation: Orientation::FlatTop, .. } => (row, col - row / 2),
+            Self::Parallelogram{ .. } | Self::Triangle{ .. } => (col, row),
+        }
+    }
+
+    fn len(&self) -> usize
+    {
+        match *self
+        {
+            Self::Rect{ width, height, .. } | Self::Parallelogram{ width, height } => width * height,
+            Self::Triangle{ size } => size * (size + 1) / 2,
+        }
+    }
+}
+
+impl<C: HexCoords> ShapeIter<C>
+{
+    pub(crate) fn rect(corner: C, width: usize, height: usize, orientation: Orientation) -> Self
+    {
+        Self::new(corner, Shape::Rect{ width, height, orientation })
+    }
+
+    pub(crate) fn parallelogram(corner: C, width: usize, height: usize) -> Self
+    {
+        Self::new(corner, Shape::Parallelogram{ width, height })
+    }
+
+    pub(crate) fn triangle(corner: C, size: usize) -> Self
+    {
+        Self::new(corner, Shape::Triangle{ size })
+    }
+
+    fn new(corner: C, shape: Shape) -> Self
+    {
+        Self{
+            corner: Axial::from(corner.to_cube()),
+            shape,
+            row: 0,
+            col: 0,
+            remaining: shape.len(),
+            _coords: PhantomData,
+        }
+    }
+}
+
+impl<C: HexCoords> Iterator for ShapeIter<C>
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (q, r) = self.shape.offset(self.row, self.col);
+        let output = self.corner + Axial::new(C::Int::from_isize(q), C::Int::from_isize(r));
+        self.remaining -= 1;
+        self.col += 1;
+        if self.col >= self.shape.row_len(self.row) {
+            self.row += 1;
+            self.col = 0;
+        }
+        Some(C::from_cube(output.into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<C: HexCoords> ExactSizeIterator for ShapeIter<C> {}
+
+impl<C: HexCoords> FusedIterator for ShapeIter<C> {}
+
+
+/// Number of tiles in a ring with the given radius
+fn ring_len(radius: usize) -> usize
+{
+    if radius == 0 { 1 } else { 6 * radius }
+}
+
+/// Number of tiles in a hexagonal area with the given radius, as generated by
+/// [`HexCoords::area_iter`] or [`HexCoords::spiral_iter`]
+pub fn area_len(radius: usize) -> usize
+{
+    1 + 3 * radius * (radius + 1)
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, AxialCoords, CubeCoords, DoubledHeightCoords, OddQCoords, OddRCoords};
+
+    /// Asserts that the size hint of an iterator is exact at every step
+    fn assert_exact_size<I: Iterator + Clone>(iter: I)
+    {
+        let mut iter = iter;
+        loop
+        {
+            let len = iter.clone().count();
+            assert_eq!((len, Some(len)), iter.size_hint());
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn ring_iter()
+    {
+        let center = axial!(2, -1);
+        assert_eq!(vec![center], AxialCoords::ring_iter(center, 0).collect::<Vec<_>>());
+        for radius in 1..5
+        {
+            let ring: Vec<_> = AxialCoords::ring_iter(center, radius).collect();
+            assert_eq!(6 * radius, ring.len());
+            assert_eq!(center + AxialCoords::Q * radius, ring[0]);
+            for (i, coords) in ring.iter().enumerate()
+            {
+                assert_eq!(radius as isize, AxialCoords::distance(center, *coords));
+                // Consecutive tiles are adjacent, including the last and the first one
+                assert_eq!(1, AxialCoords::distance(*coords, ring[(i + 1) % ring.len()]));
+            }
+            assert_exact_size(AxialCoords::ring_iter(center, radius));
+        }
+    }
+
+    #[test]
+    fn ring_clockwise()
+    {
+        let ring: Vec<_> = AxialCoords::ring_iter(AxialCoords::ZERO, 1).collect();
+        let expected: Vec<_> = HexDirection::ALL.iter().map(|dir| dir.to_axial()).collect();
+        assert_eq!(expected, ring);
+    }
+
+    #[test]
+    fn spiral_iter()
+    {
+        let center = OddRCoords::new(1, 3);
+        let spiral: Vec<_> = OddRCoords::spiral_iter(center, 3).collect();
+        assert_eq!(37, spiral.len());
+        assert_eq!(center, spiral[0]);
+        let mut last_distance = 0;
+        for coords in &spiral
+        {
+            let distance = OddRCoords::distance(center, *coords);
+            assert!(distance == last_distance || distance == last_distance + 1);
+            last_distance = distance;
+        }
+        assert_exact_size(OddRCoords::spiral_iter(center, 3));
+        assert_exact_size(OddRCoords::spiral_iter(center, 0));
+    }
+
+    /// Ensures that spiral indices match the order of [`HexCoords::spiral_iter`] and convert back
+    /// to the same tiles
+    #[test]
+    fn spiral_index()
+    {
+        let center = axial!(3, -7);
+        for (index, coords) in AxialCoords::spiral_iter(center, 6).enumerate()
+        {
+            assert_eq!(index, AxialCoords::to_spiral_index(center, coords));
+            assert_eq!(coords, AxialCoords::from_spiral_index(center, index));
+        }
+        assert_eq!(area_len(6), AxialCoords::to_spiral_index(center, center + AxialCoords::Q * 7));
+
+        let center = OddRCoords::new(-2, 5);
+        for index in [0, 1, 6, 7, 18, 19, 1000, 123_456, 10_000_000]
+        {
+            let coords = OddRCoords::from_spiral_index(center, index);
+            assert_eq!(index, OddRCoords::to_spiral_index(center, coords));
+        }
+        for radius in 1..20
+        {
+            // The last tile of each area, and the first tile of the next ring
+            let last: CubeCoords = CubeCoords::from_spiral_index(CubeCoords::ZERO, area_len(radius) - 1);
+            let first: CubeCoords = CubeCoords::from_spiral_index(CubeCoords::ZERO, area_len(radius));
+            assert_eq!(radius as isize, CubeCoords::distance(CubeCoords::ZERO, last));
+            assert_eq!(radius as isize + 1, CubeCoords::distance(CubeCoords::ZERO, first));
+        }
+    }
+
+    #[test]
+    fn area_iter()
+    {
+        let center = CubeCoords::new(-2, 1, 1);
+        for radius in 0..5
+        {
+            let area: Vec<_> = CubeCoords::area_iter(center, radius).collect();
+            let spiral: Vec<_> = CubeCoords::spiral_iter(center, radius).collect();
+            assert_eq!(spiral.len(), area.len());
+            for coords in &area
+            {
+                assert!(spiral.contains(coords));
+            }
+            assert_exact_size(CubeCoords::area_iter(center, radius));
+        }
+    }
+
+    /// Ensures that rectangles line up with the rows and columns of offset coordinates with the
+    /// same orientation
+    #[test]
+    fn rect_iter()
+    {
+        let corner = axial!(3, -2);
+        let rect: Vec<_> = AxialCoords::rect_iter(corner, 4, 3, Orientation::PointyTop).collect();
+        assert_eq!(12, rect.len());
+        let origin = OddRCoords::from(corner);
+        for coords in &rect
+        {
+            let offset = OddRCoords::from(*coords);
+            assert!((0..4).contains(&(offset.col - origin.col)));
+            assert!((0..3).contains(&(offset.row - origin.row)));
+        }
+
+        let rect: Vec<_> = OddQCoords::rect_iter(OddQCoords::ZERO, 5, 2, Orientation::FlatTop).collect();
+        assert_eq!(10, rect.len());
+        for coords in &rect
+        {
+            assert!((0..5).contains(&coords.col));
+            assert!((0..2).contains(&coords.row));
+        }
+        assert_exact_size(AxialCoords::rect_iter(corner, 4, 3, Orientation::PointyTop));
+        assert_exact_size(AxialCoords::rect_iter(corner, 0, 3, Orientation::FlatTop));
+    }
+
+    #[test]
+    fn parallelogram_iter()
+    {
+        let corner = axial!(-1, 2);
+        let shape: Vec<_> = AxialCoords::parallelogram_iter(corner, 3, 4).collect();
+        assert_eq!(12, shape.len());
+        for coords in &shape
+        {
+            assert!((0..3).contains(&(coords.q - corner.q)));
+            assert!((0..4).contains(&(coords.r - corner.r)));
+        }
+        assert_exact_size(AxialCoords::parallelogram_iter(corner, 3, 4));
+    }
+
+    #[test]
+    fn triangle_iter()
+    {
+        let corner = axial!(2, 2);
+        let shape: Vec<_> = AxialCoords::triangle_iter(corner, 4).collect();
+        assert_eq!(10, shape.len());
+        for coords in &shape
+        {
+            let (q, r) = (coords.q - corner.q, coords.r - corner.r);
+            assert!(q >= 0 && r >= 0 && q + r < 4);
+        }
+        assert!(shape.contains(&(corner + AxialCoords::Q * 3)));
+        assert!(shape.contains(&(corner + AxialCoords::R * 3)));
+        assert_eq!(0, AxialCoords::triangle_iter(corner, 0).count());
+        assert_exact_size(AxialCoords::triangle_iter(corner, 4));
+    }
+
+    #[test]
+    fn line_iter()
+    {
+        let a = DoubledHeightCoords::new(-1, 1);
+        let b = DoubledHeightCoords::new(3, -5);
+        let line: Vec<_> = DoubledHeightCoords::line_iter(a, b).collect();
+        assert_eq!(a, line[0]);
+        assert_eq!(b, line[line.len() - 1]);
+        assert_eq!(DoubledHeightCoords::distance(a, b) as usize + 1, line.len());
+        assert_exact_size(DoubledHeightCoords::line_iter(a, b));
+        assert_eq!(vec![a], DoubledHeightCoords::line_iter(a, a).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn neighbors_iter()
+    {
+        let center = axial!(-4, 1);
+        for (dir, neighbor) in HexDirection::ALL.into_iter().zip(AxialCoords::neighbors_iter(center))
+        {
+            assert_eq!(center.neighbor(dir), neighbor);
+        }
+        assert_exact_size(AxialCoords::neighbors_iter(center));
+    }
+}
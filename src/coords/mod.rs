@@ -1,25 +1,66 @@
 mod axial; pub use axial::*;
 mod cube; pub use cube::*;
-mod macros; pub use macros::*;
+mod direction; pub use direction::*;
+mod doubled; pub use doubled::*;
+mod edge; pub use edge::*;
+mod error; pub use error::*;
+mod fractional; pub use fractional::*;
+mod int; pub use int::*;
+mod iter; pub use iter::*;
+mod macros;
+mod offset; pub use offset::*;
+mod vertex; pub use vertex::*;
 
 #[cfg(feature="bevy")]
 use bevy::prelude::Vec3;
 
-use crate::Orientation;
+use crate::{Layout, Orientation};
 
 
 /// Trait for a type that can represent a coordinate on a hexagonal grid.
 /// 
-/// Included are [`AxialCoords`] and [`CubeCoords`] which implement this trait.
+/// Included are [`AxialCoords`], [`CubeCoords`], the offset coordinate types ([`OddRCoords`],
+/// [`EvenRCoords`], [`OddQCoords`] and [`EvenQCoords`]) and the doubled coordinate types
+/// ([`DoubledWidthCoords`] and [`DoubledHeightCoords`]) which implement this trait.
 pub trait HexCoords
 where Self: Clone + Copy + Sized
 {
+    /// Integer type of the components of these coordinates
+    type Int: CoordInt;
+
+    /// Converts these coordinates to [`CubeCoords`]
+    fn to_cube(&self) -> Cube<Self::Int>;
+
+    /// Converts [`CubeCoords`] to these coordinates
+    fn from_cube(cube: Cube<Self::Int>) -> Self;
+
+	/// Gets the position of the center of this tile on the X/Y plane
+    fn to_world(&self, orientation: Orientation) -> (f32, f32);
+
+	/// Gets the tile coordinates closest to the given position on the X/Y plane
+	///
+	/// # Panics
+	///
+	/// Panics if `x` or `y` isn't finite, or the tile is out of range of the coordinate type. Use
+	/// [`FractionalCube::from_world`] and [`CubeCoords::try_round`] for positions that might be.
+    fn from_world(x: f32, y: f32, orientation: Orientation) -> Self;
+
     /// Generates a contiguous line of coordinates `a` to `b`.
     /// 
     /// Resulting vector should include `a` as the first element in the array and `b` as the last
     /// element. All remaining elements in between should be adjacent coordinates and in order from
-    /// `a` to `b`.
-    fn line(a: Self, b: Self) -> Vec<Self>;
+    /// `a` to `b`. See [`HexCoords::line_iter`] to avoid allocating.
+    fn line(a: Self, b: Self) -> Vec<Self>
+    {
+        Self::line_iter(a, b).collect()
+    }
+
+    /// Lazily generates a contiguous line of coordinates from `a` to `b`, in the same order as
+    /// [`HexCoords::line`]
+    fn line_iter(a: Self, b: Self) -> LineIter<Self>
+    {
+        LineIter::new(a, b)
+    }
 
     /// Generates a "ring" of hexagon coordinates centered around the given point.
     /// 
@@ -31,18 +72,36 @@ where Self: Clone + Copy + Sized
     /// to the ring of radius `1`. Radius `3` will get the ring of tiles adjacent to a ring of
     /// radius `2`, all 3 steps from center, and so on.
     /// 
-    /// A radius of `0` returns only the center tile.
-    fn ring(center: Self, radius: usize) -> Vec<Self>;
+    /// A radius of `0` returns only the center tile. See [`HexCoords::ring_iter`] for the order of
+    /// the tiles, and to avoid allocating.
+    fn ring(center: Self, radius: usize) -> Vec<Self>
+    {
+        Self::ring_iter(center, radius).collect()
+    }
 
-    /// Generates a list of adjacent hexagons to the given `center` hexagon. Order of the resulting
-    /// list is not defined.
-    fn adjacent(center: Self) -> Vec<Self>;
+    /// Lazily generates a "ring" of hexagon coordinates centered around the given point, see
+    /// [`HexCoords::ring`]
+    /// 
+    /// The ring starts at the tile `radius` steps away from the center in [`HexDirection::PosQ`]
+    /// and goes around the center clockwise.
+    fn ring_iter(center: Self, radius: usize) -> RingIter<Self>
+    {
+        RingIter::new(center, radius)
+    }
 
-	/// Gets the position of the center of this tile on the X/Y plane
-    fn to_world(&self, orientation: Orientation) -> (f32, f32);
+    /// Generates a list of adjacent hexagons to the given `center` hexagon, in the same order as
+    /// [`HexDirection::ALL`]. See [`HexCoords::neighbors_iter`] to avoid allocating.
+    fn adjacent(center: Self) -> Vec<Self>
+    {
+        Self::neighbors_iter(center).collect()
+    }
 
-	/// Gets the tile coordinates closest to the given position on the X/Y plane
-    fn from_world(x: f32, y: f32, orientation: Orientation) -> Self;
+    /// Lazily generates the adjacent hexagons to the given `center` hexagon, in the same order as
+    /// [`HexDirection::ALL`]
+    fn neighbors_iter(center: Self) -> NeighborsIter<Self>
+    {
+        NeighborsIter::new(center)
+    }
 
     /// Generates a filled hexagonal area centered on the given `center` coordinates.
     /// 
@@ -50,30 +109,232 @@ where Self: Clone + Copy + Sized
     /// radius of `0` will return only the center tile. A radius of `1` will return the center tile
     /// and the immediately adjacent tiles, one step away from the center. A radius of `2` will
     /// return all tiles 2 steps or less from the center, and so on.
+    /// 
+    /// Tiles are in canonical spiral order, see [`HexCoords::spiral_iter`]. Use
+    /// [`HexCoords::area_iter`] to avoid allocating when the order doesn't matter.
     fn area(center: Self, radius: usize) -> Vec<Self>
     {
-        let mut output = Vec::new();
-        for i in 0..radius+1
+        Self::spiral_iter(center, radius).collect()
+    }
+
+    /// Lazily generates a filled hexagonal area centered on the given `center` coordinates, see
+    /// [`HexCoords::area`]
+    /// 
+    /// Tiles are generated column by column in axial coordinates, from the lowest `q` to the
+    /// highest and from the lowest `r` to the highest within each column, which is the cheapest way
+    /// to go through them when their order doesn't matter.
+    fn area_iter(center: Self, radius: usize) -> AreaIter<Self>
+    {
+        AreaIter::new(center, radius)
+    }
+
+    /// Lazily generates a filled hexagonal area centered on the given `center` coordinates, one
+    /// ring at a time starting from the center. See [`HexCoords::ring_iter`] for the order of the
+    /// tiles in each ring.
+    /// 
+    /// This is the canonical spiral order of the tiles around `center`: the n-th tile generated is
+    /// the one at spiral index `n`, see [`HexCoords::to_spiral_index`].
+    fn spiral_iter(center: Self, radius: usize) -> SpiralIter<Self>
+    {
+        SpiralIter::new(center, radius)
+    }
+
+    /// Generates a rectangle of tiles that is `width` tiles wide along the X-axis and `height`
+    /// tiles tall along the Y-axis on a grid with the given orientation. See
+    /// [`HexCoords::rect_iter`].
+    fn rect(corner: Self, width: usize, height: usize, orientation: Orientation) -> Vec<Self>
+    {
+        Self::rect_iter(corner, width, height, orientation).collect()
+    }
+
+    /// Lazily generates a rectangle of tiles that is `width` tiles wide along the X-axis and
+    /// `height` tiles tall along the Y-axis on a grid with the given orientation.
+    /// 
+    /// `corner` is the tile with the lowest X and Y positions in the rectangle. On pointy topped
+    /// grids, every other row starting from the second one sticks out by half a tile in the
+    /// direction of the X-axis, and similarly every other column sticks out along the Y-axis on
+    /// flat topped grids. Tiles are generated row by row, or column by column on flat topped grids.
+    fn rect_iter(corner: Self, width: usize, height: usize, orientation: Orientation) -> ShapeIter<Self>
+    {
+        ShapeIter::rect(corner, width, height, orientation)
+    }
+
+    /// Generates a parallelogram of tiles with `width` tiles along the Q axis and `height` tiles
+    /// along the R axis. See [`HexCoords::parallelogram_iter`].
+    fn parallelogram(corner: Self, width: usize, height: usize) -> Vec<Self>
+    {
+        Self::parallelogram_iter(corner, width, height).collect()
+    }
+
+    /// Lazily generates a parallelogram of tiles with `width` tiles along the Q axis and `height`
+    /// tiles along the R axis.
+    /// 
+    /// `corner` is the corner with the lowest `q` and `r`, every other tile is reached by moving
+    /// from it in [`HexDirection::PosQ`] and [`HexDirection::PosR`]. Rotate the tiles to get
+    /// parallelograms along other axes.
+    fn parallelogram_iter(corner: Self, width: usize, height: usize) -> ShapeIter<Self>
+    {
+        ShapeIter::parallelogram(corner, width, height)
+    }
+
+    /// Generates a triangle of tiles with `size` tiles along each side. See
+    /// [`HexCoords::triangle_iter`].
+    fn triangle(corner: Self, size: usize) -> Vec<Self>
+    {
+        Self::triangle_iter(corner, size).collect()
+    }
+
+    /// Lazily generates a triangle of tiles with `size` tiles along each side.
+    /// 
+    /// `corner` is one of the corners of the triangle, and the sides starting at it go in
+    /// [`HexDirection::PosQ`] and [`HexDirection::PosR`]. Rotate the tiles to get triangles
+    /// pointing other ways.
+    fn triangle_iter(corner: Self, size: usize) -> ShapeIter<Self>
+    {
+        ShapeIter::triangle(corner, size)
+    }
+
+    /// Gets the position of `coords` in the spiral around `center`, as generated by
+    /// [`HexCoords::spiral_iter`].
+    /// 
+    /// Every tile has a unique spiral index, and the tiles within `radius` of `center` are exactly
+    /// the ones with an index less than [`area_len(radius)`](area_len), so a hexagon shaped map
+    /// can be stored in a flat array indexed by spiral index. This is the inverse of
+    /// [`HexCoords::from_spiral_index`].
+    fn to_spiral_index(center: Self, coords: Self) -> usize
+    {
+        let offset = coords.to_cube() - center.to_cube();
+        let radius = Cube::distance(Cube::ZERO, offset);
+        if radius == Self::Int::ZERO {
+            return 0;
+        }
+        // Rotate the tile onto the first side of the ring, which starts at the corner in the
+        // direction of `PosQ` and walks towards `NegR`, so that its `r` component counts the steps
+        // taken along the side
+        for side in 0..6
         {
-            let mut ring = Self::ring(center, i);
-            output.append(&mut ring);
+            let rotated = offset.rotate_counterclockwise(side);
+            if rotated.q == radius && rotated.r <= Self::Int::ZERO && rotated.r > -radius {
+                let radius = radius.to_isize() as usize;
+                let step = (-rotated.r).to_isize() as usize;
+                return area_len(radius - 1) + side * radius + step;
+            }
         }
-        output
+        unreachable!("Every tile in a ring lies on one of its sides")
     }
 
+    /// Gets the tile at the given position in the spiral around `center`, as generated by
+    /// [`HexCoords::spiral_iter`]. This is the inverse of [`HexCoords::to_spiral_index`].
+    fn from_spiral_index(center: Self, index: usize) -> Self
+    {
+        if index == 0 {
+            return center;
+        }
+        // Estimate the ring the index is in by solving `area_len(radius - 1) <= index`, then
+        // correct any floating point error
+        let mut radius = (((3.0 + (12.0 * index as f64 - 3.0).sqrt()) / 6.0) as usize).max(1);
+        while area_len(radius - 1) > index {
+            radius -= 1;
+        }
+        while area_len(radius) <= index {
+            radius += 1;
+        }
+        let ring_index = index - area_len(radius - 1);
+        let (side, step) = (ring_index / radius, ring_index % radius);
+        let offset = HexDirection::PosQ.to_cube() * radius + HexDirection::NegR.to_cube() * step;
+        Self::from_cube(center.to_cube() + offset.rotate_clockwise(side))
+    }
+
+    /// Gets the tile sharing an edge with this one in the given direction
+    fn neighbor(&self, direction: HexDirection) -> Self
+    {
+        Self::from_cube(self.to_cube() + direction.to_cube())
+    }
+
+    /// Gets the closest tile in the given diagonal direction, which shares a corner with this tile
+    /// but not an edge
+    fn diagonal(&self, diagonal: HexDiagonal) -> Self
+    {
+        Self::from_cube(self.to_cube() + diagonal.to_cube())
+    }
+
+    /// Gets the direction from this tile that points most closely towards `other`.
+    /// 
+    /// When the tiles are adjacent, this is the side of this tile that the two tiles share. When
+    /// `other` lies exactly on a diagonal, the direction clockwise of the diagonal is
+    /// returned. Returns [`None`] if both tiles are the same.
+    fn direction_to(&self, other: Self) -> Option<HexDirection>
+    {
+        // Widened so that the offset and dot products can't overflow for small integer types
+        let (from, to) = (self.to_cube(), other.to_cube());
+        let offset = [
+            to.q.to_i128() - from.q.to_i128(),
+            to.r.to_i128() - from.r.to_i128(),
+            to.s.to_i128() - from.s.to_i128(),
+        ];
+        if offset == [0, 0, 0] {
+            return None;
+        }
+        // The cube coordinate dot product is proportional to the dot product on the X/Y plane, so
+        // the direction with the largest dot product is the closest one by angle
+        let dot = |dir: HexDirection| {
+            let unit = dir.to_cube::<isize>();
+            offset[0] * unit.q as i128 + offset[1] * unit.r as i128 + offset[2] * unit.s as i128
+        };
+        HexDirection::ALL.into_iter()
+            .find(|dir| dot(*dir) >= dot(dir.counterclockwise()) && dot(*dir) > dot(dir.clockwise()))
+    }
+
+    /// Rotates this tile clockwise around the origin by `steps` times 60°. See
+    /// [`CubeCoords::rotate_clockwise`].
+    fn rotate_clockwise(&self, steps: usize) -> Self
+    {
+        Self::from_cube(self.to_cube().rotate_clockwise(steps))
+    }
+
+    /// Rotates this tile counterclockwise around the origin by `steps` times 60°
+    fn rotate_counterclockwise(&self, steps: usize) -> Self
+    {
+        Self::from_cube(self.to_cube().rotate_counterclockwise(steps))
+    }
+
+    /// Reflects this tile across the given axis through the origin. See [`CubeCoords::reflect`].
+    fn reflect(&self, axis: HexAxis) -> Self
+    {
+        Self::from_cube(self.to_cube().reflect(axis))
+    }
+
+    /// Rotates this tile clockwise around `center` by `steps` times 60°
+    fn rotate_clockwise_around(&self, center: Self, steps: usize) -> Self
+    {
+        Self::from_cube(self.to_cube().rotate_clockwise_around(center.to_cube(), steps))
+    }
+
+    /// Rotates this tile counterclockwise around `center` by `steps` times 60°
+    fn rotate_counterclockwise_around(&self, center: Self, steps: usize) -> Self
+    {
+        Self::from_cube(self.to_cube().rotate_counterclockwise_around(center.to_cube(), steps))
+    }
+
+    /// Reflects this tile across the given axis through `center`
+    fn reflect_around(&self, center: Self, axis: HexAxis) -> Self
+    {
+        Self::from_cube(self.to_cube().reflect_around(center.to_cube(), axis))
+    }
+
+    /// Gets the corner positions of this tile on the X/Y plane
     fn corners(&self, orientation: Orientation) -> [(f32, f32);6]
     {
         let (center_x, center_y) = self.to_world(orientation);
         let corners = orientation.tile_corners();
-        let output_corners = [
+        [
             (center_x + corners[0].0, center_y + corners[0].1),
             (center_x + corners[1].0, center_y + corners[1].1),
             (center_x + corners[2].0, center_y + corners[2].1),
             (center_x + corners[3].0, center_y + corners[3].1),
             (center_x + corners[4].0, center_y + corners[4].1),
             (center_x + corners[5].0, center_y + corners[5].1),
-        ];
-        output_corners
+        ]
     }
 
 	/// Gets the tile nearest to the given position in Bevy Engine space
@@ -88,4 +349,42 @@ where Self: Clone + Copy + Sized
         let (x, z) = self.to_world(orientation);
         bevy::prelude::Vec3::new(x, 0.0, z)
     }
+
+	/// Gets the position of the center of this tile on the X/Y plane in the given [`Layout`]
+    fn to_world_in(&self, layout: Layout) -> (f32, f32)
+    {
+        let (x, y) = self.to_world(layout.orientation);
+        layout.transform(x, y)
+    }
+
+	/// Gets the tile coordinates closest to the given position on the X/Y plane in the given [`Layout`]
+	///
+	/// # Panics
+	///
+	/// Panics in the same cases as [`HexCoords::from_world`].
+    fn from_world_in(x: f32, y: f32, layout: Layout) -> Self
+    {
+        let (x, y) = layout.inverse_transform(x, y);
+        Self::from_world(x, y, layout.orientation)
+    }
+
+    /// Gets the corner positions of this tile on the X/Y plane in the given [`Layout`]
+    fn corners_in(&self, layout: Layout) -> [(f32, f32);6]
+    {
+        let (center_x, center_y) = self.to_world_in(layout);
+        layout.tile_corners().map(|(x, y)| (center_x + x, center_y + y))
+    }
+
+	/// Gets the tile nearest to the given position in Bevy Engine space, in the given [`Layout`]
+    #[cfg(feature="bevy")]
+    fn from_vec3_in(vec: Vec3, layout: Layout) -> Self {
+        Self::from_world_in(vec.x, vec.z, layout)
+    }
+
+	/// Gets the center position of this tile as a Bevy Engine [`Vec3`], in the given [`Layout`]
+    #[cfg(feature="bevy")]
+    fn to_vec3_in(&self, layout: Layout) -> Vec3 {
+        let (x, z) = self.to_world_in(layout);
+        bevy::prelude::Vec3::new(x, 0.0, z)
+    }
 }
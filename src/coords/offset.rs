@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, Cube, HexCoords, Orientation};
+
+
+/// Generates an offset coordinate type, along with its [`HexCoords`] implementation and its
+/// conversions to and from [`AxialCoords`](crate::AxialCoords) and [`CubeCoords`](crate::CubeCoords)
+///
+/// `$to_axial` and `$from_axial` are the functions converting a `(col, row)` pair to a `(q, r)`
+/// pair and back, which is the only thing that differs between the offset coordinate variants.
+macro_rules! offset_coords {
+    ($(#[$meta:meta])* $name:ident, $to_axial:ident, $from_axial:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[derive(Deserialize, Serialize)]
+        #[serde(bound = "I: CoordInt")]
+        pub struct $name<I = isize>
+        {
+            pub col: I,
+            pub row: I,
+        }
+
+        impl<I: CoordInt> $name<I>
+        {
+            pub const ZERO: Self = Self{ col: I::ZERO, row: I::ZERO };
+
+            pub fn new(col: I, row: I) -> Self
+            {
+                Self{ col, row }
+            }
+
+            pub fn distance(a: Self, b: Self) -> I
+            {
+                Axial::distance(a.into(), b.into())
+            }
+        }
+
+        impl<I: CoordInt> HexCoords for $name<I>
+        {
+            type Int = I;
+
+            fn to_cube(&self) -> Cube<I> {
+                Cube::from(*self)
+            }
+
+            fn from_cube(cube: Cube<I>) -> Self {
+                Self::from(cube)
+            }
+
+            fn to_world(&self, orientation: Orientation) -> (f32, f32) {
+                Axial::from(*self).to_world(orientation)
+            }
+
+            fn from_world(x: f32, y: f32, orientation: Orientation) -> Self {
+                Self::from(Axial::from_world(x, y, orientation))
+            }
+        }
+
+        impl<I: CoordInt> From<Axial<I>> for $name<I>
+        {
+            fn from(value: Axial<I>) -> Self {
+                let (col, row) = $from_axial(value.q, value.r);
+                Self{ col, row }
+            }
+        }
+
+        impl<I: CoordInt> From<Cube<I>> for $name<I>
+        {
+            fn from(value: Cube<I>) -> Self {
+                Self::from(Axial::from(value))
+            }
+        }
+
+        impl<I: CoordInt> From<$name<I>> for Axial<I>
+        {
+            fn from(value: $name<I>) -> Self {
+                let (q, r) = $to_axial(value.col, value.row);
+                Self{ q, r }
+            }
+        }
+
+        impl<I: CoordInt> From<$name<I>> for Cube<I>
+        {
+            fn from(value: $name<I>) -> Self {
+                Self::from(Axial::from(value))
+            }
+        }
+    };
+}
+
+offset_coords!(
+    /// "Odd-r" offset coordinates
+    ///
+    /// Rectangular coordinates for pointy topped grids, where every odd row is shoved right by half
+    /// a tile. Convenient for rectangular maps and for storing maps in 2D arrays, but most
+    /// operations convert to [`AxialCoords`](crate::AxialCoords) internally.
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#coordinates-offset>
+    OddRCoords, odd_r_to_axial, axial_to_odd_r
+);
+
+offset_coords!(
+    /// "Even-r" offset coordinates
+    ///
+    /// Rectangular coordinates for pointy topped grids, where every even row is shoved right by
+    /// half a tile. Convenient for rectangular maps and for storing maps in 2D arrays, but most
+    /// operations convert to [`AxialCoords`](crate::AxialCoords) internally.
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#coordinates-offset>
+    EvenRCoords, even_r_to_axial, axial_to_even_r
+);
+
+offset_coords!(
+    /// "Odd-q" offset coordinates
+    ///
+    /// Rectangular coordinates for flat topped grids, where every odd column is shoved down by half
+    /// a tile. Convenient for rectangular maps and for storing maps in 2D arrays, but most
+    /// operations convert to [`AxialCoords`](crate::AxialCoords) internally.
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#coordinates-offset>
+    OddQCoords, odd_q_to_axial, axial_to_odd_q
+);
+
+offset_coords!(
+    /// "Even-q" offset coordinates
+    ///
+    /// Rectangular coordinates for flat topped grids, where every even column is shoved down by
+    /// half a tile. Convenient for rectangular maps and for storing maps in 2D arrays, but most
+    /// operations convert to [`AxialCoords`](crate::AxialCoords) internally.
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#coordinates-offset>
+    EvenQCoords, even_q_to_axial, axial_to_even_q
+);
+
+// Conversion formulas -------------------------------------------------------------------------- //
+// <https://www.redblobgames.com/grids/hexagons/#conversions-offset>
+
+fn odd_r_to_axial<I: CoordInt>(col: I, row: I) -> (I, I)
+{
+    (col - (row - (row & I::ONE)) / I::TWO, row)
+}
+
+fn axial_to_odd_r<I: CoordInt>(q: I, r: I) -> (I, I)
+{
+    (q + (r - (r & I::ONE)) / I::TWO, r)
+}
+
+fn even_r_to_axial<I: CoordInt>(col: I, row: I) -> (I, I)
+{
+    (col - (row + (row & I::ONE)) / I::TWO, row)
+}
+
+fn axial_to_even_r<I: CoordInt>(q: I, r: I) -> (I, I)
+{
+    (q + (r + (r & I::ONE)) / I::TWO, r)
+}
+
+fn odd_q_to_axial<I: CoordInt>(col: I, row: I) -> (I, I)
+{
+    (col, row - (col - (col & I::ONE)) / I::TWO)
+}
+
+fn axial_to_odd_q<I: CoordInt>(q: I, r: I) -> (I, I)
+{
+    (q, r + (q - (q & I::ONE)) / I::TWO)
+}
+
+fn even_q_to_axial<I: CoordInt>(col: I, row: I) -> (I, I)
+{
+    (col, row - (col + (col & I::ONE)) / I::TWO)
+}
+
+fn axial_to_even_q<I: CoordInt>(q: I, r: I) -> (I, I)
+{
+    (q, r + (q + (q & I::ONE)) / I::TWO)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, cube, AxialCoords, CubeCoords, HexMap};
+
+    #[test]
+    fn odd_r()
+    {
+        assert_eq!(OddRCoords::new(0, 0), OddRCoords::from(axial!(0, 0)));
+        assert_eq!(OddRCoords::new(1, 0), OddRCoords::from(axial!(1, 0)));
+        assert_eq!(OddRCoords::new(0, 1), OddRCoords::from(axial!(0, 1)));
+        assert_eq!(OddRCoords::new(0, 2), OddRCoords::from(axial!(-1, 2)));
+        assert_eq!(OddRCoords::new(-2, -1), OddRCoords::from(axial!(-1, -1)));
+        assert_eq!(axial!(-2, 3), AxialCoords::from(OddRCoords::new(-1, 3)));
+    }
+
+    #[test]
+    fn even_r()
+    {
+        assert_eq!(EvenRCoords::new(0, 0), EvenRCoords::from(axial!(0, 0)));
+        assert_eq!(EvenRCoords::new(1, 1), EvenRCoords::from(axial!(0, 1)));
+        assert_eq!(EvenRCoords::new(0, 2), EvenRCoords::from(axial!(-1, 2)));
+        assert_eq!(EvenRCoords::new(0, -1), EvenRCoords::from(axial!(0, -1)));
+        assert_eq!(axial!(-3, 3), AxialCoords::from(EvenRCoords::new(-1, 3)));
+    }
+
+    #[test]
+    fn odd_q()
+    {
+        assert_eq!(OddQCoords::new(0, 0), OddQCoords::from(axial!(0, 0)));
+        assert_eq!(OddQCoords::new(1, 0), OddQCoords::from(axial!(1, 0)));
+        assert_eq!(OddQCoords::new(2, 0), OddQCoords::from(axial!(2, -1)));
+        assert_eq!(OddQCoords::new(-1, -1), OddQCoords::from(axial!(-1, 0)));
+        assert_eq!(axial!(3, -1), AxialCoords::from(OddQCoords::new(3, 0)));
+    }
+
+    #[test]
+    fn even_q()
+    {
+        assert_eq!(EvenQCoords::new(0, 0), EvenQCoords::from(axial!(0, 0)));
+        assert_eq!(EvenQCoords::new(1, 1), EvenQCoords::from(axial!(1, 0)));
+        assert_eq!(EvenQCoords::new(2, 0), EvenQCoords::from(axial!(2, -1)));
+        assert_eq!(EvenQCoords::new(-1, 0), EvenQCoords::from(axial!(-1, 0)));
+        assert_eq!(axial!(3, -2), AxialCoords::from(EvenQCoords::new(3, 0)));
+    }
+
+    /// Ensures that converting to offset coordinates and back results in the original coordinates
+    #[test]
+    fn round_trip()
+    {
+        for coords in CubeCoords::area(cube!(1, 2, -3), 6)
+        {
+            assert_eq!(coords, CubeCoords::from(OddRCoords::from(coords)));
+            assert_eq!(coords, CubeCoords::from(EvenRCoords::from(coords)));
+            assert_eq!(coords, CubeCoords::from(OddQCoords::from(coords)));
+            assert_eq!(coords, CubeCoords::from(EvenQCoords::from(coords)));
+        }
+    }
+
+    /// Ensures that neighbors in offset coordinates match the neighbors in axial coordinates, on
+    /// both odd and even rows/columns
+    #[test]
+    fn adjacent()
+    {
+        for center in [OddRCoords::new(0, 0), OddRCoords::new(0, 1), OddRCoords::new(-3, -1)]
+        {
+            let adjacent = OddRCoords::adjacent(center);
+            assert_eq!(6, adjacent.len());
+            for neighbor in adjacent
+            {
+                assert_eq!(1, OddRCoords::distance(center, neighbor));
+            }
+        }
+        let adjacent = OddRCoords::adjacent(OddRCoords::new(0, 1));
+        assert!(adjacent.contains(&OddRCoords::new(1, 0)));
+        assert!(adjacent.contains(&OddRCoords::new(1, 2)));
+        let adjacent = EvenQCoords::adjacent(EvenQCoords::new(1, 0));
+        assert!(adjacent.contains(&EvenQCoords::new(0, -1)));
+        assert!(adjacent.contains(&EvenQCoords::new(2, -1)));
+    }
+
+    /// Ensures that rotating and reflecting offset coordinates through [`HexCoords`] matches doing
+    /// it in cube coordinates
+    #[test]
+    fn rotate_and_reflect()
+    {
+        let center = EvenRCoords::from(cube!(0, 1, -1));
+        let coords = EvenRCoords::from(cube!(3, -1, -2));
+        assert_eq!(EvenRCoords::from(cube!(1, -2, 1)), coords.rotate_clockwise_around(center, 1));
+        assert_eq!(EvenRCoords::from(cube!(3, 0, -3)), coords.reflect_around(center, crate::HexAxis::Q));
+        assert_eq!(EvenRCoords::from(cube!(-2, 3, -1)), coords.rotate_counterclockwise(2));
+    }
+
+    #[test]
+    fn hex_map_key()
+    {
+        let mut map: HexMap<OddQCoords, u8> = HexMap::new();
+        map.insert_area(OddQCoords::new(2, 2), 1, 0);
+        assert_eq!(Some(&0), map.get(OddQCoords::new(3, 2)));
+        assert_eq!(None, map.get(OddQCoords::new(4, 2)));
+        let path = map.find_path(OddQCoords::new(1, 2), OddQCoords::new(3, 2), |_, _, _| 1.0).unwrap();
+        assert_eq!(2, path.len());
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use crate::{Axial, CoordInt, HexCoords, HexDirection, HexEdge, Layout, Orientation};
+
+
+/// Coordinates of a vertex where three tiles meet, for things like settlements on tile corners
+///
+/// Corners are numbered the same way as the corners returned by [`Orientation::tile_corners`],
+/// clockwise with the Y-axis pointing up. Every vertex is a corner of three tiles, so vertices are
+/// always stored in a canonical form, using the tile for which the vertex is corner `0` or `1`, so
+/// that equal vertices compare and hash equally no matter which tile they were created from.
+///
+/// The component type `I` defaults to `isize`, but can be any [`CoordInt`].
+///
+/// <https://www.redblobgames.com/grids/parts/#hexagon-coordinates>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "I: CoordInt", from = "HexVertexRepr<I>")]
+pub struct HexVertex<I = isize>
+{
+    tile: Axial<I>,
+    corner: usize,
+}
+
+/// Representation of [`HexVertex`] that might not be in canonical form, which is deserialized
+/// first and then converted to canonical form
+#[derive(Deserialize)]
+#[serde(bound = "I: CoordInt", rename = "HexVertex")]
+struct HexVertexRepr<I>
+{
+    tile: Axial<I>,
+    corner: usize,
+}
+
+impl<I: CoordInt> HexVertex<I>
+{
+    /// Gets the given corner of `tile`, wrapping around if `corner` is greater than `5`
+    pub fn new<C: HexCoords<Int = I>>(tile: C, corner: usize) -> Self
+    {
+        let mut tile = Axial::from(tile.to_cube());
+        let mut corner = corner % 6;
+        // Corner `k` of a tile is also corner `k - 2` of the neighbor sharing the edge in
+        // direction `k - 1`
+        while corner >= 2
+        {
+            tile = tile + HexDirection::from_index(corner + 5).to_axial();
+            corner -= 2;
+        }
+        Self{ tile, corner }
+    }
+
+    /// Gets all six corners of `tile`, in the same order as [`Orientation::tile_corners`]
+    pub fn of_tile<C: HexCoords<Int = I>>(tile: C) -> [Self;6]
+    {
+        [0, 1, 2, 3, 4, 5].map(|corner| Self::new(tile, corner))
+    }
+
+    /// Tile this vertex is stored relative to in canonical form
+    pub fn tile(&self) -> Axial<I>
+    {
+        self.tile
+    }
+
+    /// Corner of [`HexVertex::tile`] this vertex is at, which is always `0` or `1`
+    pub fn corner(&self) -> usize
+    {
+        self.corner
+    }
+
+    /// Gets the three tiles that meet at this vertex
+    pub fn tiles(&self) -> [Axial<I>;3]
+    {
+        [
+            self.tile,
+            self.tile.neighbor(HexDirection::from_index(self.corner + 5)),
+            self.tile.neighbor(HexDirection::from_index(self.corner + 4)),
+        ]
+    }
+
+    /// Gets the three edges that meet at this vertex
+    pub fn edges(&self) -> [HexEdge<I>;3]
+    {
+        let first = HexDirection::from_index(self.corner + 5);
+        let second = HexDirection::from_index(self.corner + 4);
+        [
+            HexEdge::new(self.tile, first),
+            HexEdge::new(self.tile, second),
+            // The edge between the other two tiles
+            HexEdge::new(self.tile.neighbor(first), HexDirection::from_index(self.corner + 3)),
+        ]
+    }
+
+    /// Gets the three vertices that are connected to this one by an edge
+    pub fn neighbors(&self) -> [Self;3]
+    {
+        self.edges().map(|edge| {
+            let [a, b] = edge.vertices();
+            if a == *self { b } else { a }
+        })
+    }
+
+    /// Gets the position of this vertex on the X/Y plane
+    pub fn to_world(&self, orientation: Orientation) -> (f32, f32)
+    {
+        self.tile.corners(orientation)[self.corner]
+    }
+
+    /// Gets the position of this vertex on the X/Y plane in the given [`Layout`]
+    pub fn to_world_in(&self, layout: Layout) -> (f32, f32)
+    {
+        self.tile.corners_in(layout)[self.corner]
+    }
+}
+
+impl<I: CoordInt> From<HexVertexRepr<I>> for HexVertex<I>
+{
+    fn from(value: HexVertexRepr<I>) -> Self {
+        Self::new(value.tile, value.corner)
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, DoubledWidthCoords};
+
+    /// Ensures that a vertex is the same no matter which of its tiles it was created from
+    #[test]
+    fn canonical()
+    {
+        let tile = axial!(-2, 1);
+        for corner in 0..6
+        {
+            let vertex = HexVertex::new(tile, corner);
+            assert!(vertex.corner() < 2);
+            let tiles = vertex.tiles();
+            assert!(tiles.contains(&tile));
+            for other in tiles
+            {
+                assert_eq!(1, HexVertex::of_tile(other).iter().filter(|v| **v == vertex).count());
+            }
+        }
+        assert_eq!(HexVertex::new(axial!(0, 0), 2), HexVertex::new(axial!(1, -1), 0));
+        assert_eq!(HexVertex::new(axial!(0, 0), 9), HexVertex::new(axial!(0, 0), 3));
+        assert_eq!(HexVertex::new(DoubledWidthCoords::new(2, 0), 4), HexVertex::new(axial!(1, 0), 4));
+    }
+
+    #[test]
+    fn edges_and_neighbors()
+    {
+        for vertex in HexVertex::of_tile(axial!(1, 2))
+        {
+            let edges = vertex.edges();
+            for edge in edges
+            {
+                assert!(edge.vertices().contains(&vertex));
+            }
+            assert!(edges[0] != edges[1] && edges[1] != edges[2] && edges[0] != edges[2]);
+            for neighbor in vertex.neighbors()
+            {
+                assert!(neighbor != vertex);
+                assert!(neighbor.neighbors().contains(&vertex));
+            }
+        }
+    }
+
+    /// Ensures that every tile meeting at a vertex has a corner at the vertex's position
+    #[test]
+    fn to_world()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            let layout = Layout::new(orientation).with_size(2.0, 3.0).with_origin(1.0, -1.0);
+            for vertex in HexVertex::of_tile(axial!(3, -1))
+            {
+                let (x, y) = vertex.to_world(orientation);
+                let (layout_x, layout_y) = vertex.to_world_in(layout);
+                for tile in vertex.tiles()
+                {
+                    let corners = tile.corners(orientation);
+                    assert!(corners.iter().any(|corner| (corner.0 - x).abs() < 0.0001 && (corner.1 - y).abs() < 0.0001));
+                    let corners = tile.corners_in(layout);
+                    assert!(corners.iter().any(|corner| (corner.0 - layout_x).abs() < 0.0001 && (corner.1 - layout_y).abs() < 0.0001));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn serde()
+    {
+        let vertex = HexVertex::new(axial!(3, 1), 1);
+        let json = serde_json::to_string(&vertex).unwrap();
+        assert_eq!(vertex, serde_json::from_str(&json).unwrap());
+        let json = r#"{"tile":{"q":0,"r":0},"corner":4}"#;
+        assert_eq!(HexVertex::new(axial!(0, 0), 4), serde_json::from_str(json).unwrap());
+    }
+}
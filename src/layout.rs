@@ -0,0 +1,173 @@
+use crate::Orientation;
+
+
+/// Describes how a hex grid is placed on the X/Y plane
+///
+/// The coordinate types on their own work with unit sized tiles (a distance of `1.0` from the
+/// center of a tile to any of its corners) centered on the world origin. A layout adds a tile size
+/// per axis, an origin offset and a rotation on top of that, so tiles can be sized in world units.
+/// Using different sizes for the X and Y axes produces squashed hexagons, as used by isometric
+/// style maps.
+///
+/// <https://www.redblobgames.com/grids/hexagons/implementation.html#layout>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout
+{
+    /// Orientation of the tiles in this layout
+    pub orientation: Orientation,
+
+    /// Distance from the center of a tile to its corners along the X and Y axes, before rotation
+    pub size: (f32, f32),
+
+    /// World position of the center of the tile at coordinates `(0, 0)`
+    pub origin: (f32, f32),
+
+    /// Counter-clockwise rotation of the grid around its origin, in radians
+    pub rotation: f32,
+}
+
+impl Layout
+{
+    /// Creates a new layout with unit sized tiles in the given orientation, centered on the world
+    /// origin. Positions computed with this layout match the ones computed with the orientation alone.
+    pub fn new(orientation: Orientation) -> Self
+    {
+        Self{ orientation, size: (1.0, 1.0), origin: (0.0, 0.0), rotation: 0.0 }
+    }
+
+    /// Sets the distance from the center of each tile to its corners along the X and Y axes
+    pub fn with_size(mut self, x: f32, y: f32) -> Self
+    {
+        self.size = (x, y);
+        self
+    }
+
+    /// Sets the world position of the center of the tile at coordinates `(0, 0)`
+    pub fn with_origin(mut self, x: f32, y: f32) -> Self
+    {
+        self.origin = (x, y);
+        self
+    }
+
+    /// Sets the counter-clockwise rotation of the grid around its origin, in radians
+    pub fn with_rotation(mut self, radians: f32) -> Self
+    {
+        self.rotation = radians;
+        self
+    }
+
+    /// Transforms a position relative to a unit sized grid centered on the world origin into a
+    /// world position in this layout
+    pub fn transform(&self, x: f32, y: f32) -> (f32, f32)
+    {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = (x * self.size.0, y * self.size.1);
+        (
+            x * cos - y * sin + self.origin.0,
+            x * sin + y * cos + self.origin.1,
+        )
+    }
+
+    /// Transforms a world position in this layout into a position relative to a unit sized grid
+    /// centered on the world origin. This is the inverse of [`Layout::transform`].
+    pub fn inverse_transform(&self, x: f32, y: f32) -> (f32, f32)
+    {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = (x - self.origin.0, y - self.origin.1);
+        (
+            (x * cos + y * sin) / self.size.0,
+            (-x * sin + y * cos) / self.size.1,
+        )
+    }
+
+    /// The corner positions of a tile in this layout, relative to the center of the tile
+    pub fn tile_corners(&self) -> [(f32, f32);6]
+    {
+        let (sin, cos) = self.rotation.sin_cos();
+        self.orientation.tile_corners().map(|(x, y)| {
+            let (x, y) = (x * self.size.0, y * self.size.1);
+            (x * cos - y * sin, x * sin + y * cos)
+        })
+    }
+}
+
+impl Default for Layout
+{
+    fn default() -> Self {
+        Self::new(Orientation::PointyTop)
+    }
+}
+
+impl From<Orientation> for Layout
+{
+    fn from(value: Orientation) -> Self {
+        Self::new(value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{AxialCoords, HexCoords, axial};
+    use crate::test_utils::assert_world_eq;
+
+    /// Ensures that a default unit layout gives the same results as the orientation alone
+    #[test]
+    fn unit_layout()
+    {
+        for orientation in [Orientation::PointyTop, Orientation::FlatTop]
+        {
+            let layout = Layout::new(orientation);
+            for coords in AxialCoords::area(AxialCoords::ZERO, 3)
+            {
+                assert_world_eq(coords.to_world(orientation), coords.to_world_in(layout));
+                let corners = coords.corners(orientation);
+                let layout_corners = coords.corners_in(layout);
+                for i in 0..6
+                {
+                    assert_world_eq(corners[i], layout_corners[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn size_and_origin()
+    {
+        let layout = Layout::new(Orientation::FlatTop).with_size(2.0, 0.5).with_origin(10.0, -4.0);
+        let (x, y) = axial!(1, 0).to_world(Orientation::FlatTop);
+        assert_world_eq((x * 2.0 + 10.0, y * 0.5 - 4.0), axial!(1, 0).to_world_in(layout));
+        assert_world_eq((10.0, -4.0), AxialCoords::ZERO.to_world_in(layout));
+        assert_world_eq((12.0, -4.0), AxialCoords::ZERO.corners_in(layout)[2]);
+    }
+
+    #[test]
+    fn rotation()
+    {
+        let layout = Layout::new(Orientation::PointyTop).with_rotation(std::f32::consts::FRAC_PI_2);
+        let sqrt_3 = 3.0f32.sqrt();
+        assert_world_eq((0.0, sqrt_3), axial!(1, 0).to_world_in(layout));
+    }
+
+    /// Ensures that converting coordinates to world space and back through a layout results in the
+    /// original coordinates
+    #[test]
+    fn round_trip()
+    {
+        let layouts = [
+            Layout::new(Orientation::PointyTop).with_size(32.0, 16.0).with_origin(100.0, 50.0),
+            Layout::new(Orientation::FlatTop).with_size(0.25, 0.25).with_rotation(0.4),
+            Layout::new(Orientation::FlatTop).with_size(3.0, 1.5).with_origin(-7.0, 2.0).with_rotation(-1.2),
+        ];
+        for layout in layouts
+        {
+            for coords in AxialCoords::area(axial!(2, -1), 4)
+            {
+                let (x, y) = coords.to_world_in(layout);
+                assert_eq!(coords, AxialCoords::from_world_in(x, y, layout), "{:?}", layout);
+            }
+        }
+    }
+}
@@ -32,11 +32,16 @@
 
 
 mod coords; pub use coords::*;
+mod layout; pub use layout::*;
 mod map; pub use map::*;
+mod region; pub use region::*;
+
+#[cfg(test)]
+mod test_utils;
 
 
 /// Certain algorithms require an orientation that determines how the hex grid is oriented on the X/Y plane
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Orientation
 {
     PointyTop,
@@ -138,5 +143,5 @@ mod constants
     pub const POINTY_TOP_Y_SPACING: f32 = POINTY_TOP_HEIGHT / 4.0 * 3.0;
 
 	/// Square root of 3
-    const SQRT_3: f32 = 1.73205080757;
+    const SQRT_3: f32 = 1.732_050_8;
 }
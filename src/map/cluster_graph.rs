@@ -0,0 +1,728 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{CoordInt, Cube, HexCoords, HexDirection, HexMap, HexPath, MoveCost, PathMap};
+use super::pathfinding::SearchEntry;
+
+
+/// Index of a cluster on the lattice of cluster centers
+type ClusterIndex = (isize, isize);
+
+/// Offsets to the indices of the six clusters around a cluster
+const CLUSTER_NEIGHBORS: [ClusterIndex;6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+
+/// A pair of neighboring tiles in different clusters that can be moved between, with the cost of
+/// moving across in each direction
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Crossing<C>
+{
+    inside: C,
+    outside: C,
+    cost_out: Option<f32>,
+    cost_in: Option<f32>,
+}
+
+impl<C> Crossing<C>
+where C: Copy
+{
+    /// The same crossing seen from the cluster on the other side
+    fn reversed(&self) -> Self
+    {
+        Self{ inside: self.outside, outside: self.inside, cost_out: self.cost_in, cost_in: self.cost_out }
+    }
+}
+
+
+/// Entrances of a cluster and the costs of moving between them without leaving the cluster
+#[derive(Clone, Debug)]
+struct Cluster<C>
+{
+    /// Crossings to each neighboring cluster, one for each stretch of the border between them
+    borders: HashMap<ClusterIndex, Vec<Crossing<C>>>,
+    /// Cost of the cheapest path from each entrance tile to each other entrance tile it can reach
+    paths: HashMap<C, Vec<(C, f32)>>,
+}
+
+impl<C> Default for Cluster<C>
+{
+    fn default() -> Self {
+        Self{ borders: HashMap::new(), paths: HashMap::new() }
+    }
+}
+
+
+/// Abstract graph over a [`HexMap`] split into hexagonal clusters, for hierarchical pathfinding
+/// ([HPA*](https://webdocs.cs.ualberta.ca/~mmueller/ps/hpastar.pdf)) on large maps
+///
+/// The map is tiled with hexagons of the same radius. Each stretch of border between two clusters
+/// gets a single entrance, and the costs of moving between the entrances of a cluster are worked out
+/// ahead of time. [`ClusterGraph::find_path`] then only searches the graph of entrances, and fills
+/// in the path between them one cluster at a time, which searches far fewer tiles than
+/// [`HexMap::find_path`] between distant tiles. Between two tiles of the map, paths found this way
+/// exist whenever [`HexMap::find_path`] would find one, but can cost slightly more, since they must
+/// go through the entrances.
+///
+/// The graph must be kept up to date with the map. When tiles, costs or walls change, calling
+/// [`ClusterGraph::invalidate_cluster`] for each changed tile only works out the costs for the
+/// clusters that contain them, and for neighbors whose entrances have changed.
+#[derive(Clone, Debug)]
+pub struct ClusterGraph<C>
+{
+    radius: usize,
+    clusters: HashMap<ClusterIndex, Cluster<C>>,
+}
+
+impl<C> ClusterGraph<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    /// Builds the graph for `map` with clusters of the given radius, using the provided cost
+    /// function for moves between tiles. Walls and impassable moves are handled the same way as in
+    /// [`HexMap::find_path`].
+    pub fn new<T, F, R>(map: &HexMap<C, T>, radius: usize, cost_fn: F) -> Self
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let mut graph = Self{ radius, clusters: HashMap::new() };
+        for (coords, _) in map.iter()
+        {
+            let index = graph.index_of(*coords);
+            graph.clusters.entry(index).or_default();
+        }
+        let indices = graph.clusters.keys().copied().collect::<Vec<_>>();
+        for index in indices.iter()
+        {
+            for other in graph.neighbor_indices(*index)
+            {
+                if other > *index && graph.clusters.contains_key(&other) {
+                    graph.set_border(map, *index, other, &cost_fn);
+                }
+            }
+        }
+        for index in indices
+        {
+            graph.update_paths(map, index, &cost_fn);
+        }
+        graph
+    }
+
+    /// Radius of each cluster
+    pub fn radius(&self) -> usize
+    {
+        self.radius
+    }
+
+    /// Number of clusters containing at least one tile of the map
+    pub fn cluster_count(&self) -> usize
+    {
+        self.clusters.len()
+    }
+
+    /// Gets the center of the cluster containing the given tile
+    ///
+    /// # Panics
+    ///
+    /// Panics if the center is out of range for the coordinate type, which can only happen for
+    /// tiles within the cluster radius of the limits of the integer type
+    pub fn cluster_center(&self, coords: C) -> C
+    {
+        self.center_of(self.index_of(coords))
+    }
+
+    /// Gets the entrance tiles of the cluster containing the given tile, which paths between
+    /// clusters go through
+    pub fn entrances(&self, coords: C) -> Vec<C>
+    {
+        self.clusters.get(&self.index_of(coords))
+            .map(|cluster| {
+                // A tile on a corner of the cluster can be the entrance to two neighboring clusters
+                cluster.borders.values().flatten()
+                    .map(|crossing| crossing.inside)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Updates the graph after tiles in the cluster containing `coords` were added, removed or
+    /// changed cost, or walls in or around it changed
+    ///
+    /// The entrances and costs of that cluster are worked out again, along with the costs of any
+    /// neighboring cluster whose entrances on the shared border changed. The cluster is dropped
+    /// once it has no tiles left.
+    pub fn invalidate_cluster<T, F, R>(&mut self, map: &HexMap<C, T>, coords: C, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let index = self.index_of(coords);
+        if !C::area_iter(self.center_of(index), self.radius).any(|coords| map.get(coords).is_some()) {
+            self.clusters.remove(&index);
+            for other in self.neighbor_indices(index)
+            {
+                let changed = self.clusters.get_mut(&other)
+                    .is_some_and(|cluster| cluster.borders.remove(&index).is_some());
+                if changed {
+                    self.update_paths(map, other, &cost_fn);
+                }
+            }
+            return;
+        }
+
+        self.clusters.entry(index).or_default();
+        for other in self.neighbor_indices(index)
+        {
+            if !self.clusters.contains_key(&other) {
+                continue;
+            }
+            let (low, high) = if index < other { (index, other) } else { (other, index) };
+            let before = self.clusters[&other].borders.get(&index).cloned();
+            self.set_border(map, low, high, &cost_fn);
+            if self.clusters[&other].borders.get(&index) != before.as_ref() {
+                self.update_paths(map, other, &cost_fn);
+            }
+        }
+        self.update_paths(map, index, &cost_fn);
+    }
+
+    /// Finds a path from the `start` coords to the `destination` coords on `map`, which must be the
+    /// map the graph was built for, using the same cost function the graph was built with
+    ///
+    /// The entrances that can be reached from `start` and that can reach `destination` without
+    /// leaving their clusters are searched for first, then the cheapest route through the graph of
+    /// entrances, which is then refined into a path of tiles one cluster at a time.
+    ///
+    /// Unlike [`HexMap::find_path`], which can start off the map, this returns [`None`] unless both
+    /// `start` and `destination` are on the map, or are the same tile.
+    ///
+    /// If the graph is out of date with the map, the path found might not be the one that would
+    /// be found after invalidating the changed clusters, or no path might be found at all.
+    pub fn find_path<T, F, R>(&self, map: &HexMap<C, T>, start: C, destination: C, cost_fn: F) -> Option<HexPath<C>>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        if start == destination {
+            return Some(HexPath::empty(start));
+        }
+        map.get(start)?;
+        map.get(destination)?;
+        let start_index = self.index_of(start);
+        let destination_index = self.index_of(destination);
+
+        // Costs from the start to the entrances of its cluster, and from the entrances of the
+        // destination's cluster to the destination
+        let from_start = map.reachable(start, f32::INFINITY, self.restricted(start_index, &cost_fn));
+        let mut to_destination = PathMap::default().starting_from(destination);
+        while let Some(next_coords) = to_destination.get_next_node()
+        {
+            to_destination.eval_coords_reverse(next_coords, map, self.restricted(destination_index, &cost_fn));
+            to_destination.set_coords_searched(next_coords);
+        }
+        let mut searched = from_start.len() + to_destination.searched_count();
+
+        // Search the graph of entrances, with edges from the start and into the destination
+        let mut nodes: HashMap<C, (f32, Option<C>)> = HashMap::new();
+        let mut searched_coords = HashSet::new();
+        let mut queue = BinaryHeap::new();
+        nodes.insert(start, (0.0, None));
+        queue.push(SearchEntry{ priority: 0.0, cost: 0.0, coords: start });
+        while let Some(entry) = queue.pop()
+        {
+            let coords = entry.coords;
+            if coords == destination {
+                break;
+            }
+            if !searched_coords.insert(coords) {
+                continue;
+            }
+            let index = self.index_of(coords);
+            // The start can be in a cluster that was added to the map without being invalidated
+            let Some(cluster) = self.clusters.get(&index) else {
+                continue;
+            };
+            let mut edges = Vec::new();
+            if coords == start {
+                edges.extend(cluster.borders.values().flatten()
+                    .filter_map(|crossing| from_start.cost(crossing.inside).map(|cost| (crossing.inside, cost))));
+            } else if let Some(paths) = cluster.paths.get(&coords) {
+                edges.extend(paths.iter().copied());
+            }
+            if index == destination_index {
+                edges.extend(to_destination.total_cost(coords).map(|cost| (destination, cost)));
+            }
+            edges.extend(cluster.borders.values().flatten()
+                .filter(|crossing| crossing.inside == coords)
+                .filter_map(|crossing| crossing.cost_out.map(|cost| (crossing.outside, cost))));
+
+            for (next, cost) in edges
+            {
+                let total_cost = entry.cost + cost;
+                let cheaper = nodes.get(&next).map_or(true, |(existing, _)| total_cost < *existing);
+                if cheaper && !searched_coords.contains(&next) {
+                    nodes.insert(next, (total_cost, Some(coords)));
+                    queue.push(SearchEntry{ priority: total_cost, cost: total_cost, coords: next });
+                }
+            }
+        }
+        nodes.get(&destination)?;
+        searched += searched_coords.len();
+
+        let mut waypoints = vec![destination];
+        while let Some(prev) = nodes[waypoints.last().unwrap()].1
+        {
+            waypoints.push(prev);
+        }
+        waypoints.reverse();
+
+        // Refine each step between entrances into a path of tiles
+        let mut path = HexPath::empty(start);
+        for step in waypoints.windows(2)
+        {
+            let (from, to) = (step[0], step[1]);
+            let index = self.index_of(from);
+            if index == self.index_of(to) {
+                path.append(map.find_path(from, to, self.restricted(index, &cost_fn))?);
+            } else {
+                path.push(to, nodes[&to].0 - nodes[&from].0);
+            }
+        }
+        path.add_nodes_expanded(searched);
+        Some(path)
+    }
+
+    /// Works out the crossings on the border between the clusters `low` and `high`, where `low` is
+    /// the lower index, so that the same entrances are chosen no matter which side is updated
+    fn set_border<T, F, R>(&mut self, map: &HexMap<C, T>, low: ClusterIndex, high: ClusterIndex, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let move_cost = |from: C, direction: HexDirection| {
+            if !map.can_cross(from, direction) {
+                return None;
+            }
+            match cost_fn(from, from.neighbor(direction), map).into() {
+                MoveCost::Cost(cost) => Some(cost),
+                MoveCost::Impassable => None,
+            }
+        };
+
+        let mut crossings = Vec::new();
+        for inside in C::ring_iter(self.center_of(low), self.radius)
+        {
+            if map.get(inside).is_none() {
+                continue;
+            }
+            for direction in HexDirection::ALL
+            {
+                let outside = inside.neighbor(direction);
+                if map.get(outside).is_none() || self.index_of(outside) != high {
+                    continue;
+                }
+                let cost_out = move_cost(inside, direction);
+                let cost_in = move_cost(outside, direction.opposite());
+                if cost_out.is_some() || cost_in.is_some() {
+                    crossings.push(Crossing{ inside, outside, cost_out, cost_in });
+                }
+            }
+        }
+
+        // Crossings belong to the same stretch of border if they can be crossed the same ways, and
+        // their tiles on each side are the same or can be moved between in both directions
+        let linked = |a: C, b: C| {
+            a == b || HexDirection::ALL.into_iter().any(|direction| {
+                a.neighbor(direction) == b && move_cost(a, direction).is_some()
+                    && move_cost(b, direction.opposite()).is_some()
+            })
+        };
+        let same_stretch = |a: &Crossing<C>, b: &Crossing<C>| {
+            a.cost_out.is_some() == b.cost_out.is_some() && a.cost_in.is_some() == b.cost_in.is_some()
+                && linked(a.inside, b.inside) && linked(a.outside, b.outside)
+        };
+
+        let mut entrances = Vec::new();
+        let mut grouped = vec![false; crossings.len()];
+        for first in 0..crossings.len()
+        {
+            if grouped[first] {
+                continue;
+            }
+            grouped[first] = true;
+            let mut stretch = vec![crossings[first]];
+            let mut i = 0;
+            while i < stretch.len()
+            {
+                for other in 0..crossings.len()
+                {
+                    if !grouped[other] && same_stretch(&stretch[i], &crossings[other]) {
+                        grouped[other] = true;
+                        stretch.push(crossings[other]);
+                    }
+                }
+                i += 1;
+            }
+            // The entrance is the crossing closest to the middle of the stretch
+            let spread = |crossing: &Crossing<C>| {
+                stretch.iter()
+                    .map(|other| {
+                        Cube::distance(crossing.inside.to_cube(), other.inside.to_cube()).to_isize()
+                            + Cube::distance(crossing.outside.to_cube(), other.outside.to_cube()).to_isize()
+                    })
+                    .max()
+                    .unwrap_or(0)
+            };
+            let mut entrance = stretch[0];
+            for crossing in stretch.iter().skip(1)
+            {
+                if spread(crossing) < spread(&entrance) {
+                    entrance = *crossing;
+                }
+            }
+            entrances.push(entrance);
+        }
+
+        let reversed = entrances.iter().map(Crossing::reversed).collect::<Vec<_>>();
+        for (index, other, entrances) in [(low, high, entrances), (high, low, reversed)]
+        {
+            let cluster = self.clusters.get_mut(&index).unwrap();
+            if entrances.is_empty() {
+                cluster.borders.remove(&other);
+            } else {
+                cluster.borders.insert(other, entrances);
+            }
+        }
+    }
+
+    /// Works out the costs of moving between the entrances of the cluster at `index`
+    fn update_paths<T, F, R>(&mut self, map: &HexMap<C, T>, index: ClusterIndex, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let cluster = &self.clusters[&index];
+        let entrances = cluster.borders.values().flatten()
+            .map(|crossing| crossing.inside)
+            .collect::<HashSet<_>>();
+        let mut paths = HashMap::new();
+        for entrance in entrances.iter()
+        {
+            let reachable = map.reachable(*entrance, f32::INFINITY, self.restricted(index, &cost_fn));
+            let costs = entrances.iter()
+                .filter(|other| *other != entrance)
+                .filter_map(|other| reachable.cost(*other).map(|cost| (*other, cost)))
+                .collect();
+            paths.insert(*entrance, costs);
+        }
+        self.clusters.get_mut(&index).unwrap().paths = paths;
+    }
+
+    /// Wraps `cost_fn` so that moves out of the cluster at `index` are impassable
+    fn restricted<'a, T, F, R>(&'a self, index: ClusterIndex, cost_fn: &'a F) -> impl Fn(C, C, &HexMap<C, T>) -> MoveCost + 'a
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        move |from, to, map| {
+            if self.index_of(to) == index { cost_fn(from, to, map).into() } else { MoveCost::Impassable }
+        }
+    }
+
+    /// Gets the index of the cluster containing the given tile
+    ///
+    /// Cluster centers form a lattice with basis vectors `(2r + 1, -r)` and `(r, r + 1)` in axial
+    /// coordinates, so the tile's position in lattice coordinates is rounded to the nearest
+    /// lattice point, then the clusters around it are checked for the one containing the tile.
+    /// This is worked out in i128 with exact integer division, so that tiles far from the origin
+    /// can't be misplaced by rounding errors.
+    fn index_of(&self, coords: C) -> ClusterIndex
+    {
+        let cube = coords.to_cube();
+        let (q, r) = (cube.q.to_i128(), cube.r.to_i128());
+        let radius = self.radius as i128;
+        // Odd, so the nearest lattice point is never a tie
+        let det = 3 * radius * radius + 3 * radius + 1;
+        let round = |value: i128| (2 * value + det).div_euclid(2 * det);
+        let i = round((radius + 1) * q - radius * r);
+        let j = round(radius * q + (2 * radius + 1) * r);
+        for (di, dj) in [(0, 0), (1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (-1, -1)]
+        {
+            let index = (i + di, j + dj);
+            let (center_q, center_r) = self.lattice_point(index);
+            let (dq, dr) = (q - center_q, r - center_r);
+            if (dq.abs() + dr.abs() + (dq + dr).abs()) / 2 <= radius {
+                let to_isize = |value: i128| isize::try_from(value).expect("cluster index is out of range for isize");
+                return (to_isize(index.0), to_isize(index.1));
+            }
+        }
+        unreachable!("every tile is within one step of the rounded lattice point")
+    }
+
+    /// Gets the axial coordinates of the center of the cluster at `index`
+    fn lattice_point(&self, (i, j): (i128, i128)) -> (i128, i128)
+    {
+        let radius = self.radius as i128;
+        (i * (2 * radius + 1) + j * radius, j * (radius + 1) - i * radius)
+    }
+
+    /// Gets the center of the cluster at `index`
+    fn center_of(&self, (i, j): ClusterIndex) -> C
+    {
+        let (q, r) = self.lattice_point((i as i128, j as i128));
+        C::from_cube(Cube{
+            q: C::Int::from_i128(q),
+            r: C::Int::from_i128(r),
+            s: C::Int::from_i128(-q - r),
+        })
+    }
+
+    /// Gets the indices of the six clusters around the cluster at `index`
+    fn neighbor_indices(&self, (i, j): ClusterIndex) -> [ClusterIndex;6]
+    {
+        CLUSTER_NEIGHBORS.map(|(di, dj)| (i + di, j + dj))
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{Axial, AxialCoords, Wall};
+    use crate::map::tests::{noisy_cost, noisy_cost_map};
+
+    /// Map with tiles of varying cost, a few lines of impassable tiles and a few walls
+    fn test_map() -> HexMap<AxialCoords, f32>
+    {
+        let mut map = noisy_cost_map(12);
+        for (a, b) in [((-10, 3), (4, 3)), ((2, -10), (2, 1)), ((-6, -2), (-6, 10)), ((5, 5), (9, -3))]
+        {
+            for coords in AxialCoords::line_iter(AxialCoords::new(a.0, a.1), AxialCoords::new(b.0, b.1))
+            {
+                map.insert(coords, f32::INFINITY);
+            }
+        }
+        map.insert_wall(AxialCoords::new(0, 0), HexDirection::PosQ, Wall::Solid);
+        map.insert_wall(AxialCoords::new(2, -1), HexDirection::NegR, Wall::OneWay(HexDirection::NegR));
+        map.insert_wall(AxialCoords::new(-3, 4), HexDirection::PosS, Wall::Solid);
+        map
+    }
+
+    /// Recomputes the cost of `path` step by step, checking that each step is a valid move
+    fn check_path(map: &HexMap<AxialCoords, f32>, path: &HexPath<AxialCoords>) -> f32
+    {
+        let mut prev = path.start();
+        let mut total = 0.0;
+        for (tile, cumulative) in path.tiles().iter().zip(path.cumulative_costs())
+        {
+            let direction = HexDirection::ALL.into_iter().find(|dir| prev.neighbor(*dir) == *tile).unwrap();
+            assert!(map.can_cross(prev, direction));
+            total += noisy_cost(prev, *tile, map).unwrap();
+            assert!((total - cumulative).abs() < 0.001);
+            prev = *tile;
+        }
+        total
+    }
+
+    #[test]
+    fn partition()
+    {
+        for radius in 0..5
+        {
+            let graph: ClusterGraph<AxialCoords> = ClusterGraph{ radius, clusters: HashMap::new() };
+            let mut sizes = HashMap::new();
+            for coords in AxialCoords::area_iter(AxialCoords::ZERO, 30)
+            {
+                let center = graph.cluster_center(coords);
+                assert!(AxialCoords::distance(coords, center) <= radius as isize);
+                assert_eq!(center, graph.cluster_center(center));
+                *sizes.entry(center).or_insert(0) += 1;
+            }
+            // Clusters well inside the area are whole hexagons
+            for (center, size) in sizes
+            {
+                if AxialCoords::distance(center, AxialCoords::ZERO) + (radius as isize) <= 30 {
+                    assert_eq!(crate::area_len(radius), size);
+                }
+            }
+        }
+    }
+
+    /// Ensures that tiles far from the origin are placed in the cluster containing them, where
+    /// coordinates are too large to be worked with as floats without losing precision
+    #[test]
+    fn partition_far_from_origin()
+    {
+        let far = Axial::<i64>::new(i64::MAX / 2 + 12_345, -(i64::MAX / 3));
+        for radius in [0, 1, 2, 5, 1000]
+        {
+            let graph: ClusterGraph<Axial<i64>> = ClusterGraph{ radius, clusters: HashMap::new() };
+            for coords in Axial::area_iter(far, 12)
+            {
+                let center = graph.cluster_center(coords);
+                assert!(Axial::distance(coords, center) <= radius as i64);
+                assert_eq!(center, graph.cluster_center(center));
+            }
+            // Tiles at the limits of the integer type, whose centers may not be representable
+            for coords in [Axial::new(i64::MAX, 0), Axial::new(i64::MIN + 1, i64::MAX), Axial::new(0, i64::MIN + 1)]
+            {
+                let (i, j) = graph.index_of(coords);
+                let (center_q, center_r) = graph.lattice_point((i as i128, j as i128));
+                let (dq, dr) = (coords.q as i128 - center_q, coords.r as i128 - center_r);
+                assert!((dq.abs() + dr.abs() + (dq + dr).abs()) / 2 <= radius as i128);
+            }
+        }
+    }
+
+    #[test]
+    fn find_path()
+    {
+        let map = test_map();
+        for radius in [1, 3, 5]
+        {
+            let graph = map.cluster_graph(radius, noisy_cost);
+            for start in AxialCoords::ring_iter(AxialCoords::ZERO, 11).step_by(5)
+            {
+                for destination in AxialCoords::area_iter(AxialCoords::ZERO, 12).step_by(23)
+                {
+                    let optimal = map.find_path(start, destination, noisy_cost);
+                    let path = graph.find_path(&map, start, destination, noisy_cost);
+                    assert_eq!(optimal.is_some(), path.is_some());
+                    if let (Some(optimal), Some(path)) = (optimal, path) {
+                        assert_eq!(start, path.start());
+                        assert_eq!(destination, path.destination());
+                        let cost = check_path(&map, &path);
+                        assert!((cost - path.total_cost()).abs() < 0.001);
+                        assert!(path.total_cost() >= optimal.total_cost() - 0.001);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Ensures that a path is found between every pair of tiles on a small map exactly when
+    /// searching the whole map finds one
+    #[test]
+    fn find_path_all_pairs()
+    {
+        let mut map = noisy_cost_map(3);
+        for coords in AxialCoords::line_iter(AxialCoords::new(-3, 1), AxialCoords::new(2, 1))
+        {
+            map.insert(coords, f32::INFINITY);
+        }
+        map.insert_wall(AxialCoords::new(0, 0), HexDirection::PosQ, Wall::Solid);
+        map.insert_wall(AxialCoords::new(1, -2), HexDirection::NegR, Wall::OneWay(HexDirection::NegR));
+        map.insert_wall(AxialCoords::new(3, 0), HexDirection::PosR, Wall::Solid);
+        for radius in [1, 2]
+        {
+            let graph = map.cluster_graph(radius, noisy_cost);
+            for start in AxialCoords::area_iter(AxialCoords::ZERO, 3)
+            {
+                for destination in AxialCoords::area_iter(AxialCoords::ZERO, 3)
+                {
+                    let optimal = map.find_path(start, destination, noisy_cost);
+                    let path = graph.find_path(&map, start, destination, noisy_cost);
+                    assert_eq!(optimal.is_some(), path.is_some(), "{:?} to {:?}", start, destination);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn find_path_nearby()
+    {
+        let map = test_map();
+        let graph = map.cluster_graph(4, noisy_cost);
+        let start = AxialCoords::new(-1, 0);
+
+        // A path to the start is empty, and a path within a cluster doesn't need any entrances
+        assert_eq!(Some(HexPath::empty(start)), graph.find_path(&map, start, start, noisy_cost));
+        let destination = AxialCoords::new(-1, 2);
+        assert_eq!(graph.cluster_center(start), graph.cluster_center(destination));
+        let path = graph.find_path(&map, start, destination, noisy_cost).unwrap();
+        assert_eq!(map.find_path(start, destination, noisy_cost).unwrap().total_cost(), path.total_cost());
+
+        // Impassable and missing destinations can't be reached
+        let impassable = AxialCoords::area_iter(AxialCoords::ZERO, 12).find(|c| map.get(*c) == Some(&f32::INFINITY)).unwrap();
+        assert_eq!(None, graph.find_path(&map, start, impassable, noisy_cost));
+        assert_eq!(None, graph.find_path(&map, start, AxialCoords::new(20, 0), noisy_cost));
+
+        // Tiles in clusters the graph hasn't seen yet can't be searched from until invalidated
+        let mut map = map;
+        let added = (13..24).map(|q| AxialCoords::new(q, 0)).collect::<Vec<_>>();
+        for coords in added.iter()
+        {
+            map.insert(*coords, 1.0);
+        }
+        let end = *added.last().unwrap();
+        assert!(!graph.clusters.contains_key(&graph.index_of(end)));
+        assert_eq!(None, graph.find_path(&map, end, start, noisy_cost));
+        let mut graph = graph;
+        for coords in added
+        {
+            graph.invalidate_cluster(&map, coords, noisy_cost);
+        }
+        assert!(graph.find_path(&map, end, start, noisy_cost).is_some());
+    }
+
+    /// Ensures that each entrance of a cluster is listed once, even when it is the entrance to
+    /// more than one neighboring cluster
+    #[test]
+    fn entrances()
+    {
+        let map = noisy_cost_map(8);
+        // Clusters of a single tile have that tile as the entrance to all six neighbors
+        let graph = map.cluster_graph(0, noisy_cost);
+        assert_eq!(vec![AxialCoords::ZERO], graph.entrances(AxialCoords::ZERO));
+        for radius in [1, 2, 3]
+        {
+            let graph = map.cluster_graph(radius, noisy_cost);
+            for coords in AxialCoords::area_iter(AxialCoords::ZERO, 8)
+            {
+                let entrances = graph.entrances(coords);
+                assert!(!entrances.is_empty());
+                assert_eq!(entrances.len(), entrances.iter().collect::<HashSet<_>>().len());
+            }
+        }
+    }
+
+    /// Ensures that invalidating the changed clusters gives the same graph as building it again
+    #[test]
+    fn invalidate_cluster()
+    {
+        let mut map = test_map();
+        let mut graph = map.cluster_graph(3, noisy_cost);
+        let changed = [AxialCoords::new(3, 0), AxialCoords::new(-4, 4), AxialCoords::new(0, -7)];
+        for coords in changed
+        {
+            map.insert(coords, f32::INFINITY);
+            map.insert(coords.neighbor(HexDirection::PosR), 1.0);
+            map.insert_wall(coords.neighbor(HexDirection::NegQ), HexDirection::PosS, Wall::Solid);
+        }
+        for coords in changed
+        {
+            for tile in AxialCoords::area_iter(coords, 1)
+            {
+                graph.invalidate_cluster(&map, tile, noisy_cost);
+            }
+        }
+
+        let rebuilt = map.cluster_graph(3, noisy_cost);
+        assert_eq!(rebuilt.cluster_count(), graph.cluster_count());
+        for start in AxialCoords::ring_iter(AxialCoords::ZERO, 10).step_by(6)
+        {
+            for destination in AxialCoords::area_iter(AxialCoords::ZERO, 12).step_by(31)
+            {
+                let expected = rebuilt.find_path(&map, start, destination, noisy_cost).map(|path| path.total_cost());
+                let path = graph.find_path(&map, start, destination, noisy_cost);
+                assert_eq!(expected, path.as_ref().map(|path| path.total_cost()));
+                if let Some(path) = path {
+                    check_path(&map, &path);
+                }
+            }
+        }
+
+        // Clusters are dropped once all of their tiles are gone
+        let center = graph.cluster_center(AxialCoords::new(-12, 6));
+        let mut emptied = HexMap::new();
+        for (coords, tile) in map.iter().filter(|(coords, _)| AxialCoords::distance(**coords, center) > 3)
+        {
+            emptied.insert(*coords, *tile);
+        }
+        graph.invalidate_cluster(&emptied, center, noisy_cost);
+        assert_eq!(rebuilt.cluster_count() - 1, graph.cluster_count());
+        assert!(graph.entrances(center).is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::{CoordInt, HexCoords, HexDirection, HexEdge};
+
+#[cfg(feature="bevy")]
+use bevy::prelude::Resource;
+
+
+/// A map of data stored on the edges between tiles, such as walls or rivers
+///
+/// Companion to [`HexMap`](crate::HexMap), indexed by [`HexEdge`] instead of tile coordinates.
+/// Since edges are always stored in canonical form, the data on an edge can be looked up from
+/// either of the tiles next to it.
+#[cfg_attr(feature="bevy", derive(Resource))]
+#[derive(Deserialize, Serialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct EdgeMap<T, I = isize>
+where I: CoordInt
+{
+    #[serde(with = "super::entries")]
+    map: HashMap<HexEdge<I>, T>
+}
+
+impl<T, I> EdgeMap<T, I>
+where I: CoordInt
+{
+	/// Creates a new, empty map
+    pub fn new() -> Self
+    {
+        Self{ map: HashMap::new() }
+    }
+
+	/// Gets the data on the given edge, if there is any
+    pub fn get(&self, edge: HexEdge<I>) -> Option<&T>
+    {
+        self.map.get(&edge)
+    }
+
+	/// Gets the data on the given edge mutably, if there is any
+    pub fn get_mut(&mut self, edge: HexEdge<I>) -> Option<&mut T>
+    {
+        self.map.get_mut(&edge)
+    }
+
+	/// Inserts data on the given edge
+    pub fn insert(&mut self, edge: HexEdge<I>, value: T)
+    {
+        self.map.insert(edge, value);
+    }
+
+	/// Removes the data on the given edge, returning it if there was any
+    pub fn remove(&mut self, edge: HexEdge<I>) -> Option<T>
+    {
+        self.map.remove(&edge)
+    }
+
+	/// Gets the data on each of the six edges of `tile`, in the same order as
+	/// [`HexDirection::ALL`]
+    pub fn tile_edges<C>(&self, tile: C) -> [Option<&T>;6]
+    where C: HexCoords<Int = I>
+    {
+        HexDirection::ALL.map(|dir| self.get(HexEdge::new(tile, dir)))
+    }
+
+	/// Returns an iterator of all the Edge/Value (Key/Value) pairs in this map
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, HexEdge<I>, T>
+    {
+        self.map.iter()
+    }
+}
+
+impl<T, I> Default for EdgeMap<T, I>
+where I: CoordInt
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, OddRCoords};
+
+    #[test]
+    fn get_and_insert()
+    {
+        let mut map: EdgeMap<&str> = EdgeMap::new();
+        map.insert(HexEdge::new(axial!(0, 0), HexDirection::PosQ), "wall");
+        assert_eq!(Some(&"wall"), map.get(HexEdge::new(axial!(0, 0), HexDirection::PosQ)));
+        // The same edge seen from the other tile
+        assert_eq!(Some(&"wall"), map.get(HexEdge::new(axial!(1, 0), HexDirection::NegQ)));
+        assert_eq!(None, map.get(HexEdge::new(axial!(0, 0), HexDirection::NegQ)));
+
+        *map.get_mut(HexEdge::new(axial!(1, 0), HexDirection::NegQ)).unwrap() = "door";
+        assert_eq!(Some(&"door"), map.get(HexEdge::new(axial!(0, 0), HexDirection::PosQ)));
+        assert_eq!(1, map.iter().count());
+    }
+
+    #[test]
+    fn remove()
+    {
+        let mut map: EdgeMap<&str> = EdgeMap::new();
+        map.insert(HexEdge::new(axial!(0, 0), HexDirection::PosQ), "wall");
+        assert_eq!(None, map.remove(HexEdge::new(axial!(0, 0), HexDirection::NegQ)));
+        // Removed through the tile on the other side of the edge
+        assert_eq!(Some("wall"), map.remove(HexEdge::new(axial!(1, 0), HexDirection::NegQ)));
+        assert_eq!(None, map.get(HexEdge::new(axial!(0, 0), HexDirection::PosQ)));
+        assert_eq!(None, map.remove(HexEdge::new(axial!(0, 0), HexDirection::PosQ)));
+    }
+
+    #[test]
+    fn tile_edges()
+    {
+        let mut map: EdgeMap<u8, i32> = EdgeMap::default();
+        let tile = OddRCoords::<i32>::new(2, 1);
+        map.insert(HexEdge::new(tile, HexDirection::NegR), 1);
+        map.insert(HexEdge::new(tile.neighbor(HexDirection::PosS), HexDirection::NegS), 2);
+        assert_eq!([None, None, Some(&1), None, Some(&2), None], map.tile_edges(tile));
+    }
+
+    /// Ensures that edges are stored in canonical form when deserializing, even if they weren't
+    /// written that way
+    #[test]
+    fn serde()
+    {
+        let mut map: EdgeMap<String> = EdgeMap::new();
+        map.insert(HexEdge::new(axial!(0, 0), HexDirection::PosQ), "wall".to_string());
+        map.insert(HexEdge::new(axial!(2, -1), HexDirection::NegS), "river".to_string());
+        let json = serde_json::to_string(&map).unwrap();
+        let deserialized: EdgeMap<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(2, deserialized.iter().count());
+        for (edge, value) in map.iter()
+        {
+            assert_eq!(Some(value), deserialized.get(*edge));
+        }
+
+        let json = r#"{"map":[[{"tile":{"q":1,"r":0},"direction":"NegQ"},"door"]]}"#;
+        let deserialized: EdgeMap<String> = serde_json::from_str(json).unwrap();
+        let edge = HexEdge::new(axial!(0, 0), HexDirection::PosQ);
+        assert_eq!(Some(&"door".to_string()), deserialized.get(edge));
+        assert_eq!(Some(&edge), deserialized.iter().next().map(|(edge, _)| edge));
+    }
+}
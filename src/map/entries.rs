@@ -0,0 +1,24 @@
+use std::{collections::HashMap, hash::Hash};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+
+/// Serializes a [`HashMap`] as a list of key/value pairs rather than a map, for use with
+/// `#[serde(with = "entries")]`
+///
+/// Coordinates, [`HexEdge`](crate::HexEdge) and [`HexVertex`](crate::HexVertex) are all structs,
+/// which formats such as JSON don't allow as map keys. [`HexMap`](crate::HexMap),
+/// [`EdgeMap`](crate::EdgeMap) and [`VertexMap`](crate::VertexMap) all use this, so they are
+/// stored the same way.
+pub(crate) fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where K: Serialize, V: Serialize, S: Serializer
+{
+    serializer.collect_seq(map.iter())
+}
+
+/// Deserializes a [`HashMap`] from a list of key/value pairs, see [`serialize`]
+pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>, D: Deserializer<'de>
+{
+    let entries = Vec::<(K, V)>::deserialize(deserializer)?;
+    Ok(entries.into_iter().collect())
+}
@@ -0,0 +1,266 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{HexCoords, HexDirection, HexMap, MoveCost};
+use super::pathfinding::SearchEntry;
+
+
+/// Node of a [`FlowField`], with the tile's distance to the nearest goal and the neighbor to step
+/// to in order to get there
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct FlowNode<C>
+{
+    distance: f32,
+    next: Option<C>,
+}
+
+
+/// Distance from every tile of a [`HexMap`] to the nearest of a set of goals, along with the best
+/// neighbor to step to from each tile, also known as a Dijkstra map
+///
+/// Built by a single search outwards from the goals, so any number of units can find their way to
+/// the nearest goal by following [`FlowField::next_step`] without searching for a path each. When
+/// the cost of a few tiles changes, [`FlowField::update`] only searches again from the tiles whose
+/// distances could have changed.
+///
+/// <https://www.redblobgames.com/pathfinding/tower-defense/>
+#[derive(Clone, Debug)]
+pub struct FlowField<C>
+{
+    goals: HashSet<C>,
+    nodes: HashMap<C, FlowNode<C>>,
+}
+
+impl<C> FlowField<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    /// Builds a flow field over `map` leading to the given goals, using the provided cost function
+    /// for moves between tiles. Walls and impassable moves are handled the same way as in
+    /// [`HexMap::find_path`].
+    pub fn new<T, G, F, R>(map: &HexMap<C, T>, goals: G, cost_fn: F) -> Self
+    where G: IntoIterator<Item = C>, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let mut field = Self{ goals: goals.into_iter().collect(), nodes: HashMap::new() };
+        let mut queue = BinaryHeap::new();
+        field.seed_goals(map, &mut queue, |_| true);
+        field.propagate(map, queue, cost_fn);
+        field
+    }
+
+    /// Updates the flow field after the cost of moving into or out of the given tiles has changed,
+    /// or after they were added to or removed from `map`. When a wall changes, both tiles next to
+    /// it should be given.
+    ///
+    /// Only the tiles whose path to a goal went through one of the changed tiles are searched
+    /// again, along with any tiles that can now reach a goal for less than before.
+    pub fn update<T, I, F, R>(&mut self, map: &HexMap<C, T>, changed: I, cost_fn: F)
+    where I: IntoIterator<Item = C>, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        // Every tile whose path goes through a changed tile might have a different distance now
+        let mut invalid = HashSet::new();
+        let mut to_visit: VecDeque<C> = changed.into_iter().collect();
+        while let Some(coords) = to_visit.pop_front()
+        {
+            if !invalid.insert(coords) {
+                continue;
+            }
+            for neighbor in C::neighbors_iter(coords)
+            {
+                if self.next_step(neighbor) == Some(coords) {
+                    to_visit.push_back(neighbor);
+                }
+            }
+        }
+        for coords in invalid.iter()
+        {
+            self.nodes.remove(coords);
+        }
+
+        // Search again from the tiles around the invalidated ones, which still have valid distances
+        let mut queue = BinaryHeap::new();
+        for coords in invalid.iter()
+        {
+            for neighbor in C::neighbors_iter(*coords)
+            {
+                if let Some(node) = self.nodes.get(&neighbor) {
+                    queue.push(SearchEntry{ priority: node.distance, cost: node.distance, coords: neighbor });
+                }
+            }
+        }
+        self.seed_goals(map, &mut queue, |goal| invalid.contains(goal));
+        self.propagate(map, queue, cost_fn);
+    }
+
+    /// Adds nodes for the goals on `map` that `filter` returns `true` for to the field and the
+    /// queue
+    fn seed_goals<T, P>(&mut self, map: &HexMap<C, T>, queue: &mut BinaryHeap<SearchEntry<C>>, filter: P)
+    where P: Fn(&C) -> bool
+    {
+        for goal in self.goals.iter().filter(|goal| map.get(**goal).is_some() && filter(goal))
+        {
+            self.nodes.insert(*goal, FlowNode{ distance: 0.0, next: None });
+            queue.push(SearchEntry{ priority: 0.0, cost: 0.0, coords: *goal });
+        }
+    }
+
+    /// Runs the search from the tiles in `queue` outwards, lowering the distance of every tile
+    /// that can reach a goal for less through one of them
+    ///
+    /// Unlike [`PathMap`](crate::PathMap) there is no closed set, as an update can lower the
+    /// distance of tiles that were already in the field.
+    fn propagate<T, F, R>(&mut self, map: &HexMap<C, T>, mut queue: BinaryHeap<SearchEntry<C>>, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        while let Some(entry) = queue.pop()
+        {
+            let target = entry.coords;
+            if self.distance(target).is_some_and(|distance| entry.cost > distance) {
+                continue;
+            }
+            for direction in HexDirection::ALL
+            {
+                // Moves are evaluated backwards, from the neighbor into the target
+                let source = target.neighbor(direction);
+                if map.get(source).is_none()
+                    || !map.can_cross(source, direction.opposite())
+                    || self.goals.contains(&source)
+                {
+                    continue;
+                }
+                if let MoveCost::Cost(cost) = cost_fn(source, target, map).into() {
+                    let distance = entry.cost + cost;
+                    if !self.distance(source).is_some_and(|existing| distance >= existing) {
+                        self.nodes.insert(source, FlowNode{ distance, next: Some(target) });
+                        queue.push(SearchEntry{ priority: distance, cost: distance, coords: source });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Goals the flow field leads to
+    pub fn goals(&self) -> &HashSet<C>
+    {
+        &self.goals
+    }
+
+    /// Cost of reaching the nearest goal from the given tile, or [`None`] if no goal can be reached
+    pub fn distance(&self, coords: C) -> Option<f32>
+    {
+        self.nodes.get(&coords).map(|node| node.distance)
+    }
+
+    /// Neighbor to step to from the given tile to reach the nearest goal, or [`None`] if the tile
+    /// is a goal or no goal can be reached from it
+    pub fn next_step(&self, coords: C) -> Option<C>
+    {
+        self.nodes.get(&coords).and_then(|node| node.next)
+    }
+
+    /// Follows the flow field from the given tile to the nearest goal, returning the tiles along
+    /// the way, not including the starting tile. Returns [`None`] if no goal can be reached.
+    pub fn path_from(&self, coords: C) -> Option<Vec<C>>
+    {
+        let mut node = self.nodes.get(&coords)?;
+        let mut path = Vec::new();
+        while let Some(next) = node.next
+        {
+            path.push(next);
+            node = self.nodes.get(&next).unwrap();
+        }
+        Some(path)
+    }
+
+    /// Number of tiles that can reach a goal, including the goals
+    pub fn len(&self) -> usize
+    {
+        self.nodes.len()
+    }
+
+    /// Whether no tiles can reach a goal
+    pub fn is_empty(&self) -> bool
+    {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over the tiles that can reach a goal and their distance to it, in no
+    /// particular order
+    pub fn iter(&self) -> impl Iterator<Item = (C, f32)> + '_
+    {
+        self.nodes.iter().map(|(coords, node)| (*coords, node.distance))
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, AxialCoords, Wall};
+    use crate::map::tests::{noisy_cost, noisy_cost_map};
+
+    /// Ensures that the flow field matches the flow field built from scratch
+    fn assert_same(expected: &FlowField<AxialCoords>, field: &FlowField<AxialCoords>)
+    {
+        assert_eq!(expected.len(), field.len());
+        for (coords, distance) in expected.iter()
+        {
+            assert_eq!(Some(distance), field.distance(coords), "Distance of {:?} differs", coords);
+        }
+    }
+
+    /// Ensures that every tile's distance is the cost of the cheapest path to the nearest goal,
+    /// and that following the field gives a path with that cost
+    #[test]
+    fn distances()
+    {
+        let map = noisy_cost_map(4);
+        let goals = [axial!(3, 0), axial!(-2, -1)];
+        let field = map.flow_field(goals, noisy_cost);
+        assert_eq!(61, field.len());
+        let off_map = map.flow_field(goals.into_iter().chain([axial!(10, 0)]), noisy_cost);
+        assert_eq!(None, off_map.distance(axial!(10, 0)));
+        assert_eq!(61, off_map.len());
+        assert_eq!(Some(0.0), field.distance(axial!(3, 0)));
+        assert_eq!(None, field.next_step(axial!(3, 0)));
+        assert_eq!(None, field.distance(axial!(10, 0)));
+        for coords in AxialCoords::area_iter(AxialCoords::ZERO, 4)
+        {
+            let cheapest = map.find_path_to_any(coords, goals, noisy_cost).unwrap();
+            assert_eq!(Some(cheapest.total_cost()), field.distance(coords));
+            let path = field.path_from(coords).unwrap();
+            let cost: f32 = path.iter().map(|step| map.get(*step).unwrap()).sum();
+            assert_eq!(cheapest.total_cost(), cost);
+            assert!(goals.contains(path.last().unwrap_or(&coords)));
+        }
+    }
+
+    /// Ensures that updating the flow field after tiles change gives the same result as building
+    /// it again, both when costs go up and when they go down
+    #[test]
+    fn update()
+    {
+        let mut map = noisy_cost_map(4);
+        let goals = [axial!(0, 0)];
+        let mut field = map.flow_field(goals, noisy_cost);
+
+        *map.get_mut(axial!(1, 0)).unwrap() = 10.0;
+        *map.get_mut(axial!(-1, 1)).unwrap() = 10.0;
+        field.update(&map, [axial!(1, 0), axial!(-1, 1)], noisy_cost);
+        assert_same(&map.flow_field(goals, noisy_cost), &field);
+
+        *map.get_mut(axial!(1, 0)).unwrap() = 0.5;
+        *map.get_mut(axial!(2, 2)).unwrap() = 0.5;
+        field.update(&map, [axial!(1, 0), axial!(2, 2)], noisy_cost);
+        assert_same(&map.flow_field(goals, noisy_cost), &field);
+
+        // Walls and tiles added to the map
+        map.insert_wall(axial!(0, 0), HexDirection::NegR, Wall::Solid);
+        map.insert(axial!(5, 0), 1.0);
+        field.update(&map, [axial!(0, 0), axial!(0, -1), axial!(5, 0)], noisy_cost);
+        assert_same(&map.flow_field(goals, noisy_cost), &field);
+        assert_eq!(Some(axial!(4, 0)), field.next_step(axial!(5, 0)));
+    }
+}
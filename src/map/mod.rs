@@ -1,11 +1,16 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{collections::{HashMap, HashSet}, hash::Hash, iter};
 use serde::{Deserialize, Serialize};
-use crate::{HexCoords, AxialCoords, CubeCoords};
+use crate::{HexCoords, AxialCoords, CubeCoords, HexDirection, HexEdge, Orientation};
 
 #[cfg(feature="bevy")]
 use bevy::prelude::Resource;
 
+mod cluster_graph; pub use cluster_graph::*;
+mod edge_map; pub use edge_map::*;
+mod entries;
+mod flow_field; pub use flow_field::*;
 mod pathfinding; pub use pathfinding::*;
+mod vertex_map; pub use vertex_map::*;
 
 pub type AxialMap<T> = HexMap<AxialCoords, T>;
 pub type CubeMap<T> = HexMap<CubeCoords, T>;
@@ -15,12 +20,21 @@ pub type CubeMap<T> = HexMap<CubeCoords, T>;
 /// 
 /// `C` should be a hexagonal coordinate type and represents the "key" of the map. `T` can be any
 /// type supplied by the user, and is the type of the tiles stored in the map, indexed by coordinates `C`
+///
+/// The map can also hold [`Wall`]s on the edges between tiles, which pathfinding will not cross.
 #[cfg_attr(feature="bevy", derive(Resource))]
 #[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "C: Eq + Hash + HexCoords + Serialize, T: Serialize",
+    deserialize = "C: Eq + Hash + HexCoords + Deserialize<'de>, T: Deserialize<'de>",
+))]
 pub struct HexMap<C, T>
-where C: Eq + Hash
+where C: Eq + Hash + HexCoords
 {
-    map: HashMap<C, T>
+    #[serde(with = "entries")]
+    map: HashMap<C, T>,
+    #[serde(default)]
+    walls: EdgeMap<Wall, C::Int>,
 }
 
 impl<C, T> HexMap<C, T>
@@ -29,7 +43,7 @@ where C: Copy + Eq + PartialEq + Hash + HexCoords
 	/// Creates a new, empty map
     pub fn new() -> Self
     {
-        Self{ map: HashMap::new() }
+        Self{ map: HashMap::new(), walls: EdgeMap::new() }
     }
 
 	/// Gets the tile at the given coordinates, if there is one.
@@ -60,44 +74,320 @@ where C: Copy + Eq + PartialEq + Hash + HexCoords
     pub fn insert_area(&mut self, center: C, radius: usize, tile: T)
     where C: HexCoords, T: Clone
     {
-        let area = C::area(center, radius);
-        for coord in area
+        for coord in C::area_iter(center, radius)
         {
             self.insert(coord, tile.clone());
         }
     }
 
+	/// Inserts a rectangle of tiles into the map, see [`HexCoords::rect`]
+    pub fn insert_rect(&mut self, corner: C, width: usize, height: usize, orientation: Orientation, tile: T)
+    where T: Clone
+    {
+        for coord in C::rect_iter(corner, width, height, orientation)
+        {
+            self.insert(coord, tile.clone());
+        }
+    }
+
+	/// Inserts a parallelogram of tiles into the map, see [`HexCoords::parallelogram`]
+    pub fn insert_parallelogram(&mut self, corner: C, width: usize, height: usize, tile: T)
+    where T: Clone
+    {
+        for coord in C::parallelogram_iter(corner, width, height)
+        {
+            self.insert(coord, tile.clone());
+        }
+    }
+
+	/// Inserts a triangle of tiles into the map, see [`HexCoords::triangle`]
+    pub fn insert_triangle(&mut self, corner: C, size: usize, tile: T)
+    where T: Clone
+    {
+        for coord in C::triangle_iter(corner, size)
+        {
+            self.insert(coord, tile.clone());
+        }
+    }
+
+	/// Places a wall on the edge of the tile at `coords` in the given direction, replacing any wall
+	/// already there
+    pub fn insert_wall(&mut self, coords: C, direction: HexDirection, wall: Wall)
+    {
+        self.walls.insert(HexEdge::new(coords, direction), wall);
+    }
+
+	/// Removes the wall on the edge of the tile at `coords` in the given direction, returning it if
+	/// there was one
+    pub fn remove_wall(&mut self, coords: C, direction: HexDirection) -> Option<Wall>
+    {
+        self.walls.remove(HexEdge::new(coords, direction))
+    }
+
+	/// Gets the wall on the edge of the tile at `coords` in the given direction, if there is one
+    pub fn wall(&self, coords: C, direction: HexDirection) -> Option<&Wall>
+    {
+        self.walls.get(HexEdge::new(coords, direction))
+    }
+
+	/// Gets all the walls in this map
+    pub fn walls(&self) -> &EdgeMap<Wall, C::Int>
+    {
+        &self.walls
+    }
+
+	/// Whether moving from the tile at `coords` to its neighbor in the given direction is allowed
+	/// by the walls in this map. This does not check whether either tile exists.
+    pub fn can_cross(&self, coords: C, direction: HexDirection) -> bool
+    {
+        !self.wall(coords, direction).is_some_and(|wall| wall.blocks(direction))
+    }
+
     /// Finds a path from the `start` coords to the `destination` coords on this map, using
 	/// Djikstra's algorithm with the provided cost function
-    pub fn find_path<F>(&self, start: C, destination: C, cost_fn: F) -> Option<Vec<C>>
-    where C: Copy + PartialEq, F: Fn(C, C, &HexMap<C, T>) -> f32
+	///
+	/// The returned [`HexPath`] includes the total cost of reaching each tile along the path.
+	///
+	/// The path never crosses a [`Wall`] against its direction, or makes a move for which the cost
+	/// function returns [`MoveCost::Impassable`].
+    pub fn find_path<F, R>(&self, start: C, destination: C, cost_fn: F) -> Option<HexPath<C>>
+    where C: Copy + PartialEq, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        self.find_path_astar(start, destination, 0.0, cost_fn)
+    }
+
+	/// Finds a path from the `start` coords to the `destination` coords on this map, using the A*
+	/// algorithm with the provided cost function
+	///
+	/// The search is guided towards `destination` by estimating the remaining cost as the distance
+	/// to it multiplied by `min_step_cost`, so it searches far fewer tiles than
+	/// [`HexMap::find_path`] when the destination is close. `min_step_cost` must be no more than
+	/// the cheapest cost the cost function can return, or the path found may not be the cheapest
+	/// one. With a `min_step_cost` of `0.0` this is the same as [`HexMap::find_path`].
+	///
+	/// <https://www.redblobgames.com/pathfinding/a-star/introduction.html>
+    pub fn find_path_astar<F, R>(&self, start: C, destination: C, min_step_cost: f32, cost_fn: F) -> Option<HexPath<C>>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let pathfinder = PathMap::default().towards(destination, min_step_cost).starting_from(start);
+        self.search(pathfinder, |coords| coords == destination, cost_fn)
+    }
+
+	/// Finds a path from the `start` coords to the `destination` coords on this map by searching
+	/// from both ends at once until the searches meet, which searches far fewer tiles than
+	/// [`HexMap::find_path`] for distant destinations
+	///
+	/// With a `min_step_cost` of `0.0` both searches use Djikstra's algorithm, and otherwise they
+	/// use A* with the same heuristic as [`HexMap::find_path_astar`], towards the other end of the
+	/// path. The search going backwards from the destination evaluates moves in the direction they
+	/// would be made in, so one-way walls and cost functions that aren't symmetric are handled.
+	///
+	/// Returns [`None`] if either `start` or `destination` isn't on the map.
+    pub fn find_path_bidirectional<F, R>(&self, start: C, destination: C, min_step_cost: f32, cost_fn: F) -> Option<HexPath<C>>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        self.get(start)?;
+        self.get(destination)?;
+        let mut forward = PathMap::default().towards(destination, min_step_cost).starting_from(start);
+        let mut backward = PathMap::default().towards(start, min_step_cost).starting_from(destination);
+        // Cost of the cheapest path found so far, and the coords where its two halves meet
+        let mut best: Option<(f32, C)> = None;
+        while let (Some(forward_next), Some(backward_next)) = (forward.next_priority(), backward.next_priority())
+        {
+            if let Some((best_cost, _)) = best {
+                // With A*, the priorities are only lower bounds of paths through each search's
+                // frontier, rather than exact costs that can be added together
+                let done = if min_step_cost == 0.0 {
+                    forward_next + backward_next >= best_cost
+                } else {
+                    forward_next.max(backward_next) >= best_cost
+                };
+                if done {
+                    break;
+                }
+            }
+            let is_forward = forward_next <= backward_next;
+            let (search, other) = if is_forward { (&mut forward, &backward) } else { (&mut backward, &forward) };
+            let next_coords = search.get_next_node().unwrap();
+            if is_forward {
+                search.eval_coords(next_coords, self, &cost_fn);
+            } else {
+                search.eval_coords_reverse(next_coords, self, &cost_fn);
+            }
+            search.set_coords_searched(next_coords);
+            for coords in iter::once(next_coords).chain(C::neighbors_iter(next_coords))
+            {
+                if let (Some(cost), Some(other_cost)) = (search.total_cost(coords), other.total_cost(coords)) {
+                    if best.map_or(true, |(best_cost, _)| cost + other_cost < best_cost) {
+                        best = Some((cost + other_cost, coords));
+                    }
+                }
+            }
+        }
+        best.map(|(_, meeting)| forward.trace_joined(&backward, meeting))
+    }
+
+	/// Finds a path from the `start` coords to the cheapest tile to reach for which `is_goal`
+	/// returns `true`, such as the nearest enemy or resource, using Djikstra's algorithm with the
+	/// provided cost function
+	///
+	/// If the start tile is a goal itself, the path is empty.
+    pub fn find_path_to_goal<G, F, R>(&self, start: C, is_goal: G, cost_fn: F) -> Option<HexPath<C>>
+    where G: Fn(C, &T) -> bool, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let pathfinder = PathMap::default().starting_from(start);
+        let is_goal = |coords| self.get(coords).is_some_and(|tile| is_goal(coords, tile));
+        self.search(pathfinder, is_goal, cost_fn)
+    }
+
+	/// Finds a path from the `start` coords to whichever of the `goals` is the cheapest to reach,
+	/// using Djikstra's algorithm with the provided cost function
+    pub fn find_path_to_any<G, F, R>(&self, start: C, goals: G, cost_fn: F) -> Option<HexPath<C>>
+    where G: IntoIterator<Item = C>, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let goals: HashSet<C> = goals.into_iter().collect();
+        if goals.is_empty() {
+            return None;
+        }
+        let pathfinder = PathMap::default().starting_from(start);
+        self.search(pathfinder, |coords| goals.contains(&coords), cost_fn)
+    }
+
+	/// Finds a path to the `destination` coords from whichever of the `starts` can reach it the
+	/// cheapest, using Djikstra's algorithm with the provided cost function. The start that was
+	/// chosen is given by [`HexPath::start`].
+    pub fn find_path_from_any<S, F, R>(&self, starts: S, destination: C, cost_fn: F) -> Option<HexPath<C>>
+    where S: IntoIterator<Item = C>, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let mut pathfinder = PathMap::default();
+        for start in starts
+        {
+            pathfinder = pathfinder.starting_from(start);
+        }
+        self.search(pathfinder, |coords| coords == destination, cost_fn)
+    }
+
+	/// Runs the search in `pathfinder` until it reaches coordinates for which `is_goal` returns
+	/// `true`, returning the path to them
+    fn search<G, F, R>(&self, mut pathfinder: PathMap<C>, is_goal: G, cost_fn: F) -> Option<HexPath<C>>
+    where G: Fn(C) -> bool, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
     {
-        let mut pathfinder = PathMap::default().starting_from(start);
         while let Some(next_coords) = pathfinder.get_next_node()
         {
-            if next_coords == destination {
-                return Some(pathfinder.trace_path(destination));
+            if is_goal(next_coords) {
+                return Some(pathfinder.trace(next_coords));
             }
-            pathfinder.eval_coords(next_coords, self, &cost_fn );
+            pathfinder.eval_coords(next_coords, self, &cost_fn);
             pathfinder.set_coords_searched(next_coords);
         }
         None
     }
 
+	/// Finds a path from the `start` coords to the `destination` coords for a unit that has
+	/// `budget` movement points to spend each turn, taking as few turns as possible
+	///
+	/// A unit can only enter a tile if it has enough points left this turn to pay for it, and
+	/// otherwise waits until the next turn, losing whatever points it had left like in the
+	/// Civilization games. A tile that costs more than the whole budget can be entered at the start
+	/// of a turn, using up the whole turn. Among paths taking the same number of turns, the one
+	/// spending the fewest points on the last turn is chosen.
+	///
+	/// Returns [`None`] if `budget` isn't a finite number greater than `0.0`.
+    pub fn find_path_turns<F, R>(&self, start: C, destination: C, budget: f32, cost_fn: F) -> Option<TurnPath<C>>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        if !(budget.is_finite() && budget > 0.0) {
+            return None;
+        }
+        pathfinding::find_path_turns(self, start, destination, budget, cost_fn)
+    }
+
+	/// Builds a [`ClusterGraph`] for hierarchical pathfinding on this map, with clusters of the
+	/// given radius, using the provided cost function
+    pub fn cluster_graph<F, R>(&self, radius: usize, cost_fn: F) -> ClusterGraph<C>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        ClusterGraph::new(self, radius, cost_fn)
+    }
+
+	/// Builds a [`FlowField`] leading every tile on this map to the cheapest of the `goals` to
+	/// reach from it, using the provided cost function
+    pub fn flow_field<G, F, R>(&self, goals: G, cost_fn: F) -> FlowField<C>
+    where G: IntoIterator<Item = C>, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        FlowField::new(self, goals, cost_fn)
+    }
+
+	/// Finds every tile that can be reached from `start` for a total cost of at most `budget`,
+	/// using Djikstra's algorithm with the provided cost function
+	///
+	/// The result holds the cheapest cost and path to each reachable tile, for showing a unit's
+	/// movement range and then moving it to one of the tiles in range. Walls and impassable moves
+	/// are handled the same way as in [`HexMap::find_path`].
+	///
+	/// The start is always reachable, even off the map. A negative or NaN budget can't pay for
+	/// any move, so only the start is reachable then.
+    pub fn reachable<F, R>(&self, start: C, budget: f32, cost_fn: F) -> Reachable<C>
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let mut pathfinder = PathMap::default().starting_from(start);
+        if budget.is_nan() || budget < 0.0 {
+            pathfinder.set_coords_searched(start);
+            return pathfinder.into_reachable(start);
+        }
+        while let Some(next_coords) = pathfinder.get_next_node()
+        {
+            // Nodes are searched from cheapest to most expensive, so every node left costs too much
+            if pathfinder.total_cost(next_coords).unwrap() > budget {
+                break;
+            }
+            pathfinder.eval_coords(next_coords, self, &cost_fn);
+            pathfinder.set_coords_searched(next_coords);
+        }
+        pathfinder.into_reachable(start)
+    }
+
 	/// Returns an iterator of all the Coord/Tile (Key/Value) pairs in this map
-    pub fn iter(&self) -> std::collections::hash_map::Iter<C, T>
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, C, T>
     {
         self.map.iter()
     }
 }
 
+impl<C, T> Default for HexMap<C, T>
+where C: Copy + Eq + PartialEq + Hash + HexCoords
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 
 #[cfg(test)]
 mod tests
 {
-    use crate::{AxialCoords, CubeCoords, axial, cube};
+    use crate::{AxialCoords, AxialCoords16, CubeCoords, CubeCoords32, axial, cube};
     use super::*;
 
+    /// Hexagon shaped map of the given radius with pseudo-random costs from `1.0` to `3.0`, where
+    /// each tile stores the cost of moving onto it
+    pub(super) fn noisy_cost_map(radius: usize) -> HexMap<AxialCoords, f32>
+    {
+        let mut map = HexMap::new();
+        for coords in AxialCoords::area_iter(AxialCoords::ZERO, radius)
+        {
+            map.insert(coords, ((coords.q * 7 + coords.r * 13).rem_euclid(3) + 1) as f32);
+        }
+        map
+    }
+
+    /// Cost function for [`noisy_cost_map`], where tiles with a cost that isn't finite are
+    /// impassable
+    pub(super) fn noisy_cost(_from: AxialCoords, to: AxialCoords, map: &HexMap<AxialCoords, f32>) -> Option<f32>
+    {
+        map.get(to).copied().filter(|cost| cost.is_finite())
+    }
+
     /// Ensures that [`HexMap`] can be constructed using the coordinate types
     #[test]
     fn type_compatability()
@@ -113,6 +403,62 @@ mod tests
         assert_eq!(None, cube_map.get(cube!(0, 0, 0)))
     }
 
+    #[test]
+    fn insert_shapes()
+    {
+        let mut map: HexMap<AxialCoords, u8> = HexMap::new();
+        map.insert_rect(AxialCoords::ZERO, 4, 3, Orientation::PointyTop, 1);
+        assert_eq!(12, map.iter().count());
+        map.insert_parallelogram(axial!(10, 0), 2, 3, 2);
+        assert_eq!(18, map.iter().count());
+        assert_eq!(Some(&2), map.get(axial!(11, 2)));
+        map.insert_triangle(axial!(-10, 0), 3, 3);
+        assert_eq!(24, map.iter().count());
+        assert_eq!(Some(&3), map.get(axial!(-8, 0)));
+    }
+
+    /// Ensures that [`HexMap`] and pathfinding work with coordinates using smaller integer types
+    #[test]
+    fn integer_types()
+    {
+        let mut map: HexMap<AxialCoords16, ()> = HexMap::new();
+        map.insert_area(AxialCoords16::ZERO, 3, ());
+        assert_eq!(37, map.iter().count());
+        let path = map.find_path(AxialCoords16::ZERO, AxialCoords16::new(3, -3), |_, _, _| 1.0).unwrap();
+        assert_eq!(3, path.len());
+
+        let mut map: HexMap<CubeCoords32, ()> = HexMap::new();
+        map.insert_area(CubeCoords32::ZERO, 2, ());
+        let path = map.find_path(CubeCoords32::ZERO, CubeCoords32::new(-2, 0, 2), |_, _, _| 1.0).unwrap();
+        assert_eq!(2, path.len());
+    }
+
+    /// Ensures that [`HexMap`] round trips through JSON, storing its tiles as key/value pairs the
+    /// same way as its walls in [`EdgeMap`]
+    #[test]
+    fn serde()
+    {
+        let mut map: HexMap<AxialCoords, String> = HexMap::new();
+        map.insert(axial!(0, 0), "grass".to_string());
+        map.insert(axial!(1, -1), "water".to_string());
+        map.insert_wall(axial!(0, 0), HexDirection::PosQ, Wall::Solid);
+        let json = serde_json::to_value(&map).unwrap();
+        for entries in [&json["map"], &json["walls"]["map"]]
+        {
+            let entries = entries.as_array().unwrap();
+            assert!(!entries.is_empty());
+            assert!(entries.iter().all(|entry| entry.as_array().is_some_and(|pair| pair.len() == 2)));
+        }
+
+        let deserialized: HexMap<AxialCoords, String> = serde_json::from_value(json).unwrap();
+        assert_eq!(2, deserialized.iter().count());
+        for (coords, tile) in map.iter()
+        {
+            assert_eq!(Some(tile), deserialized.get(*coords));
+        }
+        assert_eq!(Some(&Wall::Solid), deserialized.wall(axial!(1, 0), HexDirection::NegQ));
+    }
+
     mod pathfinding
     {
         use super::*;
@@ -191,13 +537,10 @@ mod tests
             assert_eq!(cube!(2, 0, -2), path[3]);
         }
 
-        /// Ensures that the most cost efficient path is chosen between tiles,
-        /// even when it isn't the straight path
-        #[test]
-        fn cost_efficient_path()
+        /// Map filled with expensive tiles, with a squiggly path of cheap tiles from `(-2, 0, 2)`
+        /// to `(2, 0, -2)`
+        fn cost_efficient_map() -> HexMap<CubeCoords, PathTestTile>
         {
-            let start: CubeCoords = cube!(-2, 0, 2);
-            let end: CubeCoords = cube!(2, 0, -2);
             // initialize map filled with expensive tiles
             let mut map: HexMap<CubeCoords, PathTestTile> = HexMap::new();
             map.insert_area(CubeCoords::ZERO, 2, PathTestTile::Expensive);
@@ -209,6 +552,30 @@ mod tests
             map.insert(cube!(0, 1, -1), PathTestTile::Cheap);
             map.insert(cube!(1, 1, -2), PathTestTile::Cheap);
             map.insert(cube!(2, 0, -2), PathTestTile::Cheap);
+            map
+        }
+
+        /// Total cost of following `path` from `start`
+        fn path_cost(map: &HexMap<CubeCoords, PathTestTile>, start: CubeCoords, path: &[CubeCoords]) -> f32
+        {
+            let mut prev = start;
+            let mut cost = 0.0;
+            for coords in path
+            {
+                cost += cost_fn(prev, *coords, map);
+                prev = *coords;
+            }
+            cost
+        }
+
+        /// Ensures that the most cost efficient path is chosen between tiles,
+        /// even when it isn't the straight path
+        #[test]
+        fn cost_efficient_path()
+        {
+            let start: CubeCoords = cube!(-2, 0, 2);
+            let end: CubeCoords = cube!(2, 0, -2);
+            let map = cost_efficient_map();
             
             let path = map.find_path(start, end, cost_fn).expect("Expected to find path between start and end, but `None` was returned");
             assert_eq!(6, path.len());
@@ -220,6 +587,264 @@ mod tests
             assert_eq!(cube!(2, 0, -2), path[5]);
         }
 
+        /// Ensures that the path's costs match the costs of each step along it
+        #[test]
+        fn path_costs()
+        {
+            let map = cost_efficient_map();
+            let path = map.find_path(cube!(-2, 0, 2), cube!(0, 0, 0), cost_fn).unwrap();
+            assert_eq!(&[0.5, 1.0, 1.5], path.cumulative_costs());
+            assert_eq!(1.5, path.total_cost());
+            assert!(path.nodes_expanded() > 0);
+
+            let path = map.find_path(cube!(-2, 0, 2), cube!(-1, 0, 1), cost_fn).unwrap();
+            assert_eq!(&[2.0], path.cumulative_costs());
+        }
+
+        /// Ensures that A* finds paths with the same cost as Djikstra's algorithm, between every
+        /// pair of tiles in the cost efficient path map
+        #[test]
+        fn astar_matches_dijkstra()
+        {
+            let map = cost_efficient_map();
+            let path = map.find_path_astar(cube!(-2, 0, 2), cube!(2, 0, -2), 0.5, cost_fn).unwrap();
+            assert_eq!(3.0, path.total_cost());
+            assert_eq!(3.0, path_cost(&map, cube!(-2, 0, 2), path.tiles()));
+
+            for start in CubeCoords::area_iter(CubeCoords::ZERO, 2)
+            {
+                for end in CubeCoords::area_iter(CubeCoords::ZERO, 2)
+                {
+                    let dijkstra = map.find_path(start, end, cost_fn).unwrap();
+                    let astar = map.find_path_astar(start, end, 0.5, cost_fn).unwrap();
+                    assert_eq!(dijkstra.total_cost(), astar.total_cost());
+                    assert_eq!(path_cost(&map, start, dijkstra.tiles()), path_cost(&map, start, astar.tiles()));
+                    assert_eq!(astar.tiles().last(), dijkstra.tiles().last());
+                    assert!(astar.nodes_expanded() <= dijkstra.nodes_expanded());
+                }
+            }
+        }
+
+        /// Ensures that only tiles within the budget are reachable, and that the paths to them are
+        /// the cheapest ones
+        #[test]
+        fn reachable()
+        {
+            let map = cost_efficient_map();
+            let start = cube!(-2, 0, 2);
+            let reachable = map.reachable(start, 1.5, cost_fn);
+            assert_eq!(start, reachable.start());
+            assert_eq!(4, reachable.len());
+            assert_eq!(Some(0.0), reachable.cost(start));
+            assert_eq!(Some(1.5), reachable.cost(cube!(0, 0, 0)));
+            assert_eq!(None, reachable.cost(cube!(-1, 0, 1)));
+            let path = reachable.path_to(cube!(0, 0, 0)).unwrap();
+            assert_eq!(&[cube!(-1, -1, 2), cube!(0, -1, 1), cube!(0, 0, 0)], path.tiles());
+            assert_eq!(map.find_path(start, cube!(0, 0, 0), cost_fn).unwrap().tiles(), path.tiles());
+            assert!(reachable.path_to(cube!(0, 1, -1)).is_none());
+
+            let reachable = map.reachable(start, 2.0, cost_fn);
+            assert!(reachable.contains(cube!(-1, 0, 1)));
+            assert!(reachable.contains(cube!(0, 1, -1)));
+            assert!(!reachable.contains(cube!(-2, 2, 0)));
+            assert!(reachable.iter().all(|(_, cost)| cost <= 2.0));
+            assert_eq!(reachable.len(), reachable.region().len());
+
+            assert_eq!(1, map.reachable(start, 0.0, cost_fn).len());
+            for budget in [-1.0, f32::NAN]
+            {
+                let reachable = map.reachable(start, budget, cost_fn);
+                assert_eq!(1, reachable.len());
+                assert_eq!(Some(0.0), reachable.cost(start));
+            }
+        }
+
+        /// Ensures that bidirectional search finds paths with the same cost as searching in one
+        /// direction, between every pair of tiles in the cost efficient path map
+        #[test]
+        fn bidirectional_matches_dijkstra()
+        {
+            let mut map = cost_efficient_map();
+            map.insert_wall(cube!(0, 0, 0), HexDirection::PosR, Wall::OneWay(HexDirection::NegR));
+            map.insert_wall(cube!(0, -1, 1), HexDirection::NegQ, Wall::Solid);
+            for start in CubeCoords::area_iter(CubeCoords::ZERO, 2)
+            {
+                for end in CubeCoords::area_iter(CubeCoords::ZERO, 2)
+                {
+                    let dijkstra = map.find_path(start, end, cost_fn).unwrap();
+                    for min_step_cost in [0.0, 0.5]
+                    {
+                        let path = map.find_path_bidirectional(start, end, min_step_cost, cost_fn).unwrap();
+                        assert_eq!(dijkstra.total_cost(), path.total_cost(), "{:?} to {:?}", start, end);
+                        assert_eq!(dijkstra.total_cost(), path_cost(&map, start, path.tiles()));
+                        assert_eq!(start, path.start());
+                        assert_eq!(end, path.destination());
+                    }
+                }
+            }
+            let mut map = cost_efficient_map();
+            map.insert(cube!(5, 0, -5), PathTestTile::Cheap);
+            assert!(map.find_path_bidirectional(cube!(0, 0, 0), cube!(5, 0, -5), 0.0, cost_fn).is_none());
+            // Off the map
+            assert!(map.find_path_bidirectional(cube!(0, 0, 0), cube!(3, 0, -3), 0.0, cost_fn).is_none());
+            assert!(map.find_path_bidirectional(cube!(3, 0, -3), cube!(0, 0, 0), 0.5, cost_fn).is_none());
+        }
+
+        /// Ensures that searching from both ends searches fewer tiles than searching from one
+        #[test]
+        fn bidirectional_expands_less()
+        {
+            let mut map: HexMap<CubeCoords, PathTestTile> = HexMap::new();
+            map.insert_area(CubeCoords::ZERO, 20, PathTestTile::Cheap);
+            let start = cube!(-20, 0, 20);
+            let end = cube!(20, 0, -20);
+            let dijkstra = map.find_path(start, end, cost_fn).unwrap();
+            let bidirectional = map.find_path_bidirectional(start, end, 0.0, cost_fn).unwrap();
+            assert_eq!(dijkstra.total_cost(), bidirectional.total_cost());
+            assert!(bidirectional.nodes_expanded() < dijkstra.nodes_expanded());
+        }
+
+        /// Ensures that the cheapest goal to reach is chosen, rather than the closest one
+        #[test]
+        fn nearest_goal()
+        {
+            let map = cost_efficient_map();
+            let start = cube!(-2, 0, 2);
+            let path = map.find_path_to_any(start, [cube!(2, 0, -2), cube!(0, 0, 0), cube!(-1, 0, 1)], cost_fn).unwrap();
+            assert_eq!(cube!(0, 0, 0), path.destination());
+            assert_eq!(1.5, path.total_cost());
+            assert!(map.find_path_to_any(start, [], cost_fn).is_none());
+            assert!(map.find_path_to_any(start, [cube!(5, 0, -5)], cost_fn).is_none());
+            assert!(map.find_path_to_any(start, [start, cube!(0, 0, 0)], cost_fn).unwrap().is_empty());
+
+            let path = map.find_path_to_goal(cube!(0, 0, 0), |_, tile| matches!(tile, PathTestTile::Expensive), cost_fn).unwrap();
+            assert_eq!(1, path.len());
+            assert_eq!(2.0, path.total_cost());
+            let path = map.find_path_to_goal(cube!(0, 0, 0), |coords, _| coords.q == 2, cost_fn).unwrap();
+            assert_eq!(cube!(2, 0, -2), path.destination());
+            assert_eq!(1.5, path.total_cost());
+            assert!(map.find_path_to_goal(cube!(0, 0, 0), |coords, _| coords.q == 3, cost_fn).is_none());
+        }
+
+        /// Ensures that the start that can reach the destination the cheapest is chosen
+        #[test]
+        fn multiple_starts()
+        {
+            let map = cost_efficient_map();
+            let path = map.find_path_from_any([cube!(-2, 0, 2), cube!(2, 0, -2)], cube!(1, 1, -2), cost_fn).unwrap();
+            assert_eq!(cube!(2, 0, -2), path.start());
+            assert_eq!(&[cube!(1, 1, -2)], path.tiles());
+            let path = map.find_path_from_any([cube!(-2, 0, 2), cube!(2, 0, -2)], cube!(0, -1, 1), cost_fn).unwrap();
+            assert_eq!(cube!(-2, 0, 2), path.start());
+            assert_eq!(1.0, path.total_cost());
+            assert!(map.find_path_from_any([], cube!(0, 0, 0), cost_fn).is_none());
+        }
+
+        /// Ensures that a path is split into turns, with leftover points lost when the next tile
+        /// costs more than what's left
+        #[test]
+        fn turns()
+        {
+            let mut map: HexMap<AxialCoords, f32> = HexMap::new();
+            for (q, cost) in [0.0, 1.0, 1.0, 2.0, 1.0, 5.0].into_iter().enumerate()
+            {
+                map.insert(AxialCoords::new(q as isize, 0), cost);
+            }
+            let step_cost = |_, to, map: &HexMap<AxialCoords, f32>| *map.get(to).unwrap();
+            let path = map.find_path_turns(axial!(0, 0), axial!(5, 0), 3.0, step_cost).unwrap();
+            assert_eq!(5, path.len());
+            assert_eq!(&[0, 0, 1, 1, 2], path.tile_turns());
+            assert_eq!(&[2.0, 1.0, 1.0, 0.0, 0.0], path.remaining_points());
+            assert_eq!(3, path.turn_count());
+            assert_eq!(vec![&[axial!(1, 0), axial!(2, 0)][..], &[axial!(3, 0), axial!(4, 0)], &[axial!(5, 0)]], path.turns());
+            assert_eq!(vec![axial!(2, 0), axial!(4, 0), axial!(5, 0)], path.stops());
+
+            let path = map.find_path_turns(axial!(0, 0), axial!(0, 0), 3.0, step_cost).unwrap();
+            assert!(path.is_empty());
+            assert_eq!(0, path.turn_count());
+            assert!(path.stops().is_empty());
+            assert!(map.find_path_turns(axial!(0, 0), axial!(6, 0), 3.0, step_cost).is_none());
+
+            // Budgets that aren't finite and greater than zero
+            for budget in [0.0, -1.0, f32::NAN, f32::INFINITY]
+            {
+                assert!(map.find_path_turns(axial!(0, 0), axial!(5, 0), budget, step_cost).is_none());
+            }
+        }
+
+        /// Ensures that multi-turn paths never take more turns than following the cheapest path
+        #[test]
+        fn turns_fewer_than_cheapest_path()
+        {
+            let map = noisy_cost_map(4);
+            let budget = 4.0;
+            for end in AxialCoords::area_iter(AxialCoords::ZERO, 4)
+            {
+                let turn_path = map.find_path_turns(AxialCoords::ZERO, end, budget, noisy_cost).unwrap();
+                // Follow the cheapest path with the same rules
+                let cheapest = map.find_path(AxialCoords::ZERO, end, noisy_cost).unwrap();
+                let (mut turns, mut left) = (0, budget);
+                for coords in cheapest.tiles()
+                {
+                    let cost = *map.get(*coords).unwrap();
+                    if cost > left && left < budget {
+                        turns += 1;
+                        left = budget;
+                    }
+                    left -= cost.min(left);
+                }
+                let cheapest_turns = if cheapest.is_empty() { 0 } else { turns + 1 };
+                assert!(turn_path.turn_count() <= cheapest_turns);
+            }
+        }
+
+        /// Ensures that paths go around walls, and only cross one-way walls in their direction
+        #[test]
+        fn walls()
+        {
+            let mut map: HexMap<CubeCoords, PathTestTile> = HexMap::new();
+            map.insert_area(CubeCoords::ZERO, 2, PathTestTile::Cheap);
+            let start = cube!(0, 0, 0);
+            let end = cube!(2, 0, -2);
+            assert_eq!(2, map.find_path(start, end, cost_fn).unwrap().len());
+
+            // wall off the straight path, so the path has to go around
+            map.insert_wall(cube!(0, 0, 0), HexDirection::PosQ, Wall::Solid);
+            map.insert_wall(cube!(1, 0, -1), HexDirection::PosS, Wall::Solid);
+            map.insert_wall(cube!(1, 0, -1), HexDirection::NegR, Wall::Solid);
+            map.insert_wall(cube!(1, 0, -1), HexDirection::NegS, Wall::Solid);
+            map.insert_wall(cube!(1, 0, -1), HexDirection::PosR, Wall::Solid);
+            let path = map.find_path(start, end, cost_fn).unwrap();
+            assert_eq!(3, path.len());
+            assert!(!path.contains(&cube!(1, 0, -1)));
+
+            // a one-way wall can only be crossed in its direction
+            assert_eq!(Some(Wall::Solid), map.remove_wall(cube!(1, 0, -1), HexDirection::NegQ));
+            map.insert_wall(cube!(0, 0, 0), HexDirection::PosQ, Wall::OneWay(HexDirection::NegQ));
+            assert_eq!(3, map.find_path(start, end, cost_fn).unwrap().len());
+            assert_eq!(1, map.find_path(cube!(1, 0, -1), start, cost_fn).unwrap().len());
+            assert!(map.can_cross(cube!(1, 0, -1), HexDirection::NegQ));
+            assert!(!map.can_cross(start, HexDirection::PosQ));
+        }
+
+        /// Ensures that tiles the cost function returns [`MoveCost::Impassable`] for are never
+        /// part of a path
+        #[test]
+        fn impassable()
+        {
+            let mut map: HexMap<CubeCoords, Option<f32>> = HexMap::new();
+            map.insert_area(CubeCoords::ZERO, 1, Some(1.0));
+            map.insert(cube!(1, 0, -1), None);
+            map.insert(cube!(1, 1, -2), Some(1.0));
+            map.insert(cube!(2, 0, -2), Some(1.0));
+            let path = map.find_path(cube!(0, 0, 0), cube!(2, 0, -2), |_, to, map| *map.get(to).unwrap());
+            assert_eq!(Some(vec![cube!(0, 1, -1), cube!(1, 1, -2), cube!(2, 0, -2)]), path.map(HexPath::into_tiles));
+            let path = map.find_path(cube!(0, 0, 0), cube!(2, 0, -2), |_, to, _| {
+                if to == cube!(2, 0, -2) { MoveCost::Impassable } else { MoveCost::Cost(1.0) }
+            });
+            assert_eq!(None, path);
+        }
+
         /// Ensures that [`None`] is returned when no path can be found between
         /// the start and end
         #[test]
@@ -1,9 +1,329 @@
 use std::{
-    collections::{HashSet, HashMap},
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet, HashMap},
     hash::Hash,
+    ops::Index,
 };
 
-use crate::{HexCoords, HexMap};
+use serde::{Deserialize, Serialize};
+use crate::{CoordInt, Cube, HexCoords, HexDirection, HexMap, HexRegion};
+
+
+/// Result of a pathfinding cost function for moving from one tile to another
+///
+/// Cost functions can return an [`f32`] or an [`Option<f32>`] instead, which convert into
+/// [`MoveCost::Cost`], or [`MoveCost::Impassable`] for [`None`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoveCost
+{
+    /// The move is possible for the given cost
+    Cost(f32),
+    /// The move is not possible, and will never be part of a path
+    Impassable,
+}
+
+impl From<f32> for MoveCost
+{
+    fn from(value: f32) -> Self {
+        Self::Cost(value)
+    }
+}
+
+impl From<Option<f32>> for MoveCost
+{
+    fn from(value: Option<f32>) -> Self {
+        value.map_or(Self::Impassable, Self::Cost)
+    }
+}
+
+
+/// A wall on the edge between two tiles, which pathfinding will not cross
+///
+/// Walls are stored in a [`HexMap`] with [`HexMap::insert_wall`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum Wall
+{
+    /// Blocks movement across the edge in both directions, like a wall or a cliff
+    Solid,
+    /// Only allows movement across the edge in the given direction, like a ledge that can be
+    /// jumped down but not climbed back up
+    OneWay(HexDirection),
+}
+
+impl Wall
+{
+    /// Whether this wall blocks moving across it in the given direction
+    pub fn blocks(&self, direction: HexDirection) -> bool
+    {
+        match self {
+            Self::Solid => true,
+            Self::OneWay(allowed) => *allowed != direction,
+        }
+    }
+}
+
+
+/// A path found by pathfinding, along with what it costs to follow it
+///
+/// The path doesn't include the tile it starts from, so a path from a tile to itself is empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexPath<C>
+{
+    start: C,
+    tiles: Vec<C>,
+    costs: Vec<f32>,
+    nodes_expanded: usize,
+}
+
+impl<C> HexPath<C>
+where C: Copy
+{
+    /// Creates an empty path at `start`, for building a path out of several parts
+    pub(crate) fn empty(start: C) -> Self
+    {
+        Self{ start, tiles: Vec::new(), costs: Vec::new(), nodes_expanded: 0 }
+    }
+
+    /// Adds a step onto the end of the path
+    pub(crate) fn push(&mut self, coords: C, step_cost: f32)
+    {
+        self.costs.push(self.total_cost() + step_cost);
+        self.tiles.push(coords);
+    }
+
+    /// Adds `other` onto the end of the path, which should start where this path ends
+    pub(crate) fn append(&mut self, other: HexPath<C>)
+    {
+        let offset = self.total_cost();
+        self.tiles.extend(other.tiles);
+        self.costs.extend(other.costs.into_iter().map(|cost| cost + offset));
+        self.nodes_expanded += other.nodes_expanded;
+    }
+
+    /// Adds to the number of tiles counted as expanded while searching for this path
+    pub(crate) fn add_nodes_expanded(&mut self, count: usize)
+    {
+        self.nodes_expanded += count;
+    }
+
+    /// Tile the path starts from, which isn't part of [`HexPath::tiles`]
+    pub fn start(&self) -> C
+    {
+        self.start
+    }
+
+    /// Tile the path ends at, which is the start of the path if the path is empty
+    pub fn destination(&self) -> C
+    {
+        self.tiles.last().copied().unwrap_or(self.start)
+    }
+
+    /// Tiles along the path in order, ending with the destination
+    pub fn tiles(&self) -> &[C]
+    {
+        &self.tiles
+    }
+
+    /// Consumes the path, returning the tiles along it
+    pub fn into_tiles(self) -> Vec<C>
+    {
+        self.tiles
+    }
+
+    /// Total cost of reaching each tile in [`HexPath::tiles`] from the start of the path
+    pub fn cumulative_costs(&self) -> &[f32]
+    {
+        &self.costs
+    }
+
+    /// Total cost of following the whole path
+    pub fn total_cost(&self) -> f32
+    {
+        self.costs.last().copied().unwrap_or(0.0)
+    }
+
+    /// Number of tiles whose neighbors were evaluated while searching for this path
+    pub fn nodes_expanded(&self) -> usize
+    {
+        self.nodes_expanded
+    }
+
+    /// Number of steps in the path
+    pub fn len(&self) -> usize
+    {
+        self.tiles.len()
+    }
+
+    /// Whether the path has no steps, which is the case when it starts at its destination
+    pub fn is_empty(&self) -> bool
+    {
+        self.tiles.is_empty()
+    }
+
+    /// Whether the path goes through the given tile, not counting the tile it starts from
+    pub fn contains(&self, coords: &C) -> bool
+    where C: PartialEq
+    {
+        self.tiles.contains(coords)
+    }
+}
+
+impl<C> Index<usize> for HexPath<C>
+{
+    type Output = C;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.tiles[index]
+    }
+}
+
+
+/// A path found by multi-turn pathfinding, split into the turns it takes to follow it
+///
+/// Returned by [`HexMap::find_path_turns`]. Like [`HexPath`], the path doesn't include the tile it
+/// starts from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TurnPath<C>
+{
+    tiles: Vec<C>,
+    turns: Vec<usize>,
+    remaining: Vec<f32>,
+    nodes_expanded: usize,
+}
+
+impl<C> TurnPath<C>
+where C: Copy
+{
+    /// Tiles along the path in order, ending with the destination
+    pub fn tiles(&self) -> &[C]
+    {
+        &self.tiles
+    }
+
+    /// Turn each tile in [`TurnPath::tiles`] is reached in, starting from `0` for the current turn
+    pub fn tile_turns(&self) -> &[usize]
+    {
+        &self.turns
+    }
+
+    /// Movement points left after reaching each tile in [`TurnPath::tiles`]
+    pub fn remaining_points(&self) -> &[f32]
+    {
+        &self.remaining
+    }
+
+    /// Number of turns it takes to follow the path, which is `0` for an empty path
+    pub fn turn_count(&self) -> usize
+    {
+        self.turns.last().map_or(0, |turn| turn + 1)
+    }
+
+    /// Tiles moved through in each turn
+    pub fn turns(&self) -> Vec<&[C]>
+    {
+        let mut turns = Vec::new();
+        let mut start = 0;
+        for i in 1..=self.tiles.len()
+        {
+            if i == self.tiles.len() || self.turns[i] != self.turns[start] {
+                turns.push(&self.tiles[start..i]);
+                start = i;
+            }
+        }
+        turns
+    }
+
+    /// Tile the unit stops on at the end of each turn, the last of which is the destination
+    pub fn stops(&self) -> Vec<C>
+    {
+        self.turns().iter().map(|turn| turn[turn.len() - 1]).collect()
+    }
+
+    /// Number of tiles whose neighbors were evaluated while searching for this path
+    pub fn nodes_expanded(&self) -> usize
+    {
+        self.nodes_expanded
+    }
+
+    /// Number of steps in the path
+    pub fn len(&self) -> usize
+    {
+        self.tiles.len()
+    }
+
+    /// Whether the path has no steps, which is the case when it starts at its destination
+    pub fn is_empty(&self) -> bool
+    {
+        self.tiles.is_empty()
+    }
+}
+
+
+/// Every tile that can be reached from a starting tile within a movement budget, along with the
+/// cheapest cost and path to each of them
+///
+/// Returned by [`HexMap::reachable`], so that a unit's movement range can be shown and the path to
+/// any tile in it traced without searching again.
+#[derive(Clone, Debug)]
+pub struct Reachable<C>
+{
+    start: C,
+    nodes: HashMap<C, PathNode<C>>,
+}
+
+impl<C> Reachable<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    /// Tile the search started from
+    pub fn start(&self) -> C
+    {
+        self.start
+    }
+
+    /// Number of reachable tiles, including the starting tile
+    pub fn len(&self) -> usize
+    {
+        self.nodes.len()
+    }
+
+    /// Whether no tiles are reachable, which is never the case since the starting tile always is
+    pub fn is_empty(&self) -> bool
+    {
+        self.nodes.is_empty()
+    }
+
+    /// Whether the given tile can be reached within the budget
+    pub fn contains(&self, coords: C) -> bool
+    {
+        self.nodes.contains_key(&coords)
+    }
+
+    /// Cheapest cost of reaching the given tile, or [`None`] if it can't be reached within the budget
+    pub fn cost(&self, coords: C) -> Option<f32>
+    {
+        self.nodes.get(&coords).map(|node| node.total_cost)
+    }
+
+    /// Cheapest path to the given tile, or [`None`] if it can't be reached within the budget
+    pub fn path_to(&self, coords: C) -> Option<HexPath<C>>
+    {
+        self.contains(coords).then(|| trace_nodes(&self.nodes, coords, self.nodes.len()))
+    }
+
+    /// Returns an iterator over the reachable tiles and the cost of reaching each of them, in no
+    /// particular order
+    pub fn iter(&self) -> impl Iterator<Item = (C, f32)> + '_
+    {
+        self.nodes.iter().map(|(coords, node)| (*coords, node.total_cost))
+    }
+
+    /// Creates a region containing every reachable tile
+    pub fn region(&self) -> HexRegion<C>
+    {
+        self.nodes.keys().copied().collect()
+    }
+}
 
 
 /// Node used for pathfinding. The node graph of the [`PathMap`] struct uses this type for its nodes.
@@ -22,27 +342,73 @@ impl<C> Default for PathNode<C>
 }
 
 
+/// Entry in the queue of coordinates to search in a [`PathMap`], [`FlowField`](crate::FlowField) or
+/// [`ClusterGraph`](crate::ClusterGraph)
+///
+/// Entries are ordered so that the entry with the lowest priority is the greatest, making
+/// [`BinaryHeap`] a min-heap. Entries with equal priority are ordered by highest cost first, which
+/// for A* prefers nodes closer to the destination.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SearchEntry<C>
+{
+    /// Total cost of the node when this entry was queued, plus the estimated cost to the destination
+    pub(crate) priority: f32,
+    /// Total cost of the node when this entry was queued, used to detect outdated entries
+    pub(crate) cost: f32,
+    pub(crate) coords: C,
+}
+
+impl<C> PartialEq for SearchEntry<C>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C> Eq for SearchEntry<C> {}
+
+impl<C> PartialOrd for SearchEntry<C>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for SearchEntry<C>
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority)
+            .then_with(|| self.cost.total_cmp(&other.cost))
+    }
+}
+
+
 /// Contains the data needed while calculating a path from a [`HexMap`](crate::HexMap)
 /// 
-/// Acts as a node graph of pathfinding nodes for the pathfinding algorithm, which for the moment
-/// is [just Djikstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm)
+/// Acts as a node graph of pathfinding nodes for the pathfinding algorithm, which is either
+/// [Djikstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) or, when given a
+/// destination with [`PathMap::towards`], [A*](https://en.wikipedia.org/wiki/A*_search_algorithm)
+///
+/// Coordinates to search are kept in a binary heap. Rather than removing a coordinate's entry from
+/// the heap when its node gets cheaper, a new entry is pushed and the outdated one is skipped when
+/// it reaches the top of the heap.
 #[derive(Clone, Debug)]
 pub struct PathMap<C>
 {
-    /// Set of coordinates that still have yet to be searched and require evaluation
+    /// Queue of coordinates that still have yet to be searched and require evaluation
     /// 
-    /// The `coords_to_search` set and `searched_coords` set are MUTUALLY EXCLUSIVE. A coordinate
-    /// that's in one set should NOT be in the other.
-    coords_to_search: HashSet<C>,
+    /// May contain outdated entries for coordinates that have since been searched, or whose node
+    /// has been given a lower cost, which are discarded by [`PathMap::get_next_node`].
+    coords_to_search: BinaryHeap<SearchEntry<C>>,
 
     /// Set of coordinates that have been searched and no longer need to be evaluated
-    /// 
-    /// The `coords_to_search` set and `searched_coords` set are MUTUALLY EXCLUSIVE. A coordinate
-    /// that's in one set should NOT be in the other.
     searched_coords: HashSet<C>,
 
     /// Map of the actual pathfinding nodes, with their costs and references to their previous nodes
     nodes: HashMap<C, PathNode<C>>,
+
+    /// Destination and minimum step cost used to estimate the remaining cost of a path for A*
+    target: Option<(C, f32)>,
 }
 
 impl<C> PathMap<C>
@@ -55,8 +421,24 @@ where C: Clone + Copy + Eq + Hash + HexCoords
         self
     }
 
+    /// Directs the search towards `destination` using A*
+    ///
+    /// Nodes are searched in order of their total cost plus the distance to `destination`
+    /// multiplied by `min_step_cost`, which must be no more than the cost of the cheapest possible
+    /// move for the search to find the cheapest path.
+    pub fn towards(mut self, destination: C, min_step_cost: f32) -> Self
+    {
+        self.target = Some((destination, min_step_cost));
+        let entries = std::mem::take(&mut self.coords_to_search);
+        for entry in entries
+        {
+            self.queue(entry.coords, entry.cost);
+        }
+        self
+    }
+
     /// Adds a new pathfinding node to the pathmap, adding the coordinates of the new node to the
-    /// `coords_to_search` set. If a node already exists at the given coordinates, it is overwritten.
+    /// `coords_to_search` queue. If a node already exists at the given coordinates, it is overwritten.
     /// 
     /// Use this function ONLY if you know that the node at the given coordinates has NOT been
     /// searched yet. This could cause searched nodes to accidentally become unsearched during
@@ -65,21 +447,75 @@ where C: Clone + Copy + Eq + Hash + HexCoords
     /// or with nodes for unit testing.
     fn add_node(&mut self, coords: C, node: PathNode<C>)
     {
-        self.coords_to_search.insert(coords);
+        self.queue(coords, node.total_cost);
         self.nodes.insert(coords, node);
     }
 
+    /// Pushes an entry for the given coordinates onto the `coords_to_search` queue
+    fn queue(&mut self, coords: C, cost: f32)
+    {
+        let estimate = match self.target {
+            Some((destination, min_step_cost)) => {
+                let distance = Cube::distance(coords.to_cube(), destination.to_cube());
+                distance.to_f32() * min_step_cost
+            },
+            None => 0.0,
+        };
+        self.coords_to_search.push(SearchEntry{ priority: cost + estimate, cost, coords });
+    }
+
     /// Evaluates the given coordinates against its neighbors, updating any neighbors that can be
     /// reached from this coordinate for lower cost than their existing previous coords.
-    pub fn eval_coords<F, T>(&mut self, source: C, map: &HexMap<C, T>, cost_fn: F)
-    where F: Fn(C, C, &HexMap<C, T>) -> f32
-    {
-        let adjacent_coords = HexCoords::adjacent(source);
-        let source_node = self.get_node(source).unwrap().clone();
-        for neighbor_coord in adjacent_coords {
-            if let Some(_neighbor_tile) = map.get(neighbor_coord) {
-                let move_cost = source_node.total_cost + cost_fn(source, neighbor_coord, map);
-                self.eval_move(source, neighbor_coord, move_cost);
+    ///
+    /// Neighbors that aren't in the map, that have already been searched, that are behind a
+    /// [`Wall`], or that the cost function returns [`MoveCost::Impassable`] for are skipped.
+    pub fn eval_coords<F, R, T>(&mut self, source: C, map: &HexMap<C, T>, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        self.eval_coords_with(source, map, cost_fn, |total, cost| total + cost);
+    }
+
+    /// Evaluates the given coordinates against its neighbors for a search going backwards from the
+    /// destination, updating any neighbors that can reach these coordinates for lower cost than
+    /// their existing next coords.
+    ///
+    /// The previous coords of each node point towards the destination the search started from,
+    /// and moves are evaluated in the direction they would be made in, so walls and cost functions
+    /// don't need to be symmetric.
+    pub fn eval_coords_reverse<F, R, T>(&mut self, target: C, map: &HexMap<C, T>, cost_fn: F)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+    {
+        let target_cost = self.get_node(target).unwrap().total_cost;
+        for direction in HexDirection::ALL {
+            let neighbor_coord = target.neighbor(direction);
+            if self.searched_coords.contains(&neighbor_coord)
+                || map.get(neighbor_coord).is_none()
+                || !map.can_cross(neighbor_coord, direction.opposite())
+            {
+                continue;
+            }
+            if let MoveCost::Cost(cost) = cost_fn(neighbor_coord, target, map).into() {
+                self.eval_move(target, neighbor_coord, target_cost + cost);
+            }
+        }
+    }
+
+    /// Evaluates the given coordinates against its neighbors, using `accumulate` to get the total
+    /// cost of a neighbor from the total cost of `source` and the cost of the move
+    fn eval_coords_with<F, R, T, A>(&mut self, source: C, map: &HexMap<C, T>, cost_fn: F, accumulate: A)
+    where F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>, A: Fn(f32, f32) -> f32
+    {
+        let source_cost = self.get_node(source).unwrap().total_cost;
+        for direction in HexDirection::ALL {
+            let neighbor_coord = source.neighbor(direction);
+            if self.searched_coords.contains(&neighbor_coord)
+                || map.get(neighbor_coord).is_none()
+                || !map.can_cross(source, direction)
+            {
+                continue;
+            }
+            if let MoveCost::Cost(cost) = cost_fn(source, neighbor_coord, map).into() {
+                self.eval_move(source, neighbor_coord, accumulate(source_cost, cost));
             }
         }
     }
@@ -89,43 +525,65 @@ where C: Clone + Copy + Eq + Hash + HexCoords
     /// the source node given here instead.
     fn eval_move(&mut self, source: C, dest: C, cost: f32)
     {
-        if let Some(node) = self.nodes.get_mut(&dest) {
-            if cost < node.total_cost {
-                node.total_cost = cost;
-                node.prev_coords = Some(source);
-            }
-        } else {
-            let new_node = PathNode{ total_cost: cost, prev_coords: Some(source) };
-            self.insert_node(dest, new_node);
-        }
+        self.insert_node(dest, PathNode{ total_cost: cost, prev_coords: Some(source) });
     }
 
     /// Traces a path to the given coordinates, so long as those coordinates have been given a path node
     pub fn trace_path(&self, dest: C) -> Vec<C>
     {
-        let mut path = Vec::new();
-        let mut next_coords = Some(dest);
+        self.trace(dest).into_tiles()
+    }
+
+    /// Traces a path to the given coordinates along with its costs, so long as those coordinates
+    /// have been given a path node
+    pub fn trace(&self, dest: C) -> HexPath<C>
+    {
+        trace_nodes(&self.nodes, dest, self.searched_count())
+    }
+
+    /// Traces a path through the coordinates where this search met `backward`, a search going
+    /// backwards from the destination with [`PathMap::eval_coords_reverse`]
+    pub fn trace_joined(&self, backward: &PathMap<C>, meeting: C) -> HexPath<C>
+    {
+        let mut path = self.trace(meeting);
+        let total_cost = path.total_cost() + backward.total_cost(meeting).unwrap();
+        let mut next_coords = backward.get_node(meeting).unwrap().prev_coords;
         while let Some(c) = next_coords {
-            let node = self.nodes.get(&c).unwrap();
-            if node.prev_coords.is_some() {
-                path.push(c);
-            }
+            let node = backward.get_node(c).unwrap();
+            path.tiles.push(c);
+            path.costs.push(total_cost - node.total_cost);
             next_coords = node.prev_coords;
         }
-        path.reverse();
+        path.nodes_expanded += backward.searched_count();
         path
     }
 
+    /// Total cost of the node at the given coordinates, if there is one
+    pub fn total_cost(&self, coords: C) -> Option<f32>
+    {
+        self.get_node(coords).map(|node| node.total_cost)
+    }
+
+    /// Consumes the map, keeping only the nodes that have been searched as the tiles reachable from
+    /// `start`
+    pub fn into_reachable(self, start: C) -> Reachable<C>
+    {
+        let searched_coords = self.searched_coords;
+        let mut nodes = self.nodes;
+        nodes.retain(|coords, _| searched_coords.contains(coords));
+        Reachable{ start, nodes }
+    }
+
     /// Adds a new pathfinding node to the map if it does not exist. If it does exist, the existing
     /// node's total cost is compared with the new node's cost, and if the new node's is lower, the
-    /// existing node is replaced.
+    /// existing node is replaced and queued to be searched again at its new cost.
     pub fn insert_node(&mut self, coords: C, new_node: PathNode<C>)
     {
         if let Some(existing_node) = self.get_node(coords)
         {
             if new_node.total_cost < existing_node.total_cost
             {
-                self.nodes.insert(coords, new_node);
+                self.add_node(coords, new_node);
             }
         } else {
             self.add_node(coords, new_node);
@@ -140,59 +598,234 @@ where C: Clone + Copy + Eq + Hash + HexCoords
     }
 
     /// Returns the coordinates of the next node to be evaluated, chosen from the `coords_to_search`
-    /// set.
+    /// queue, discarding any outdated entries on the way.
     /// 
     /// If no nodes remain to be searched, this function returns `None`.
-    pub fn get_next_node(&self) -> Option<C>
+    pub fn get_next_node(&mut self) -> Option<C>
     {
-        let mut best_coords = None;
-        let mut lowest_cost = 0.0;
-
-        for coords in self.coords_to_search.iter()
+        while let Some(entry) = self.coords_to_search.peek()
         {
-            let node = self.nodes.get(coords).unwrap();
-            if best_coords.is_none() || node.total_cost < lowest_cost
-            {
-                best_coords = Some(*coords);
-                lowest_cost = node.total_cost;
+            let outdated = self.searched_coords.contains(&entry.coords)
+                || self.nodes.get(&entry.coords).is_some_and(|node| entry.cost > node.total_cost);
+            if !outdated {
+                return Some(entry.coords);
             }
+            self.coords_to_search.pop();
         }
+        None
+    }
 
-        best_coords
+    /// Priority of the node that [`PathMap::get_next_node`] returns, which is its total cost plus
+    /// the estimated cost to the destination when searching with A*
+    pub fn next_priority(&mut self) -> Option<f32>
+    {
+        self.get_next_node()?;
+        self.coords_to_search.peek().map(|entry| entry.priority)
     }
 
-    /// Moves the given coords from the `coords_to_search` set to the `searched_coords` set
+    /// Adds the given coords to the `searched_coords` set, so that they are no longer evaluated
     pub fn set_coords_searched(&mut self, searched_coords: C)
     {
-        self.coords_to_search.remove(&searched_coords);
         self.searched_coords.insert(searched_coords);
     }
+
+    /// Number of coordinates that have been searched so far
+    pub fn searched_count(&self) -> usize
+    {
+        self.searched_coords.len()
+    }
+}
+
+/// Cost of reaching a tile in multi-turn pathfinding, see [`HexMap::find_path_turns`]
+///
+/// Costs are ordered by the turn first, and then by the points spent in that turn. The turn is
+/// kept as an integer rather than folded into the points, so that long paths and large budgets
+/// can't lose track of which turn a tile is reached in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TurnCost
+{
+    turn: usize,
+    spent: f32,
+}
+
+impl TurnCost
+{
+    const START: Self = Self{ turn: 0, spent: 0.0 };
+
+    /// Cost after making a move costing `cost` from here, for a unit with `budget` movement points
+    /// each turn
+    ///
+    /// A move that costs more than the points left this turn waits until the next turn, and the
+    /// leftover points are lost. A move that costs more than the whole budget can still be made at
+    /// the start of a turn, and uses up the whole turn.
+    fn after(self, cost: f32, budget: f32) -> Self
+    {
+        if self.spent + cost <= budget {
+            Self{ turn: self.turn, spent: self.spent + cost }
+        } else if self.spent == 0.0 {
+            // Moves costing more than the whole budget use up a full turn
+            Self{ turn: self.turn, spent: budget }
+        } else {
+            Self{ turn: self.turn + 1, spent: cost.min(budget) }
+        }
+    }
+}
+
+impl PartialOrd for TurnCost
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.turn, self.spent).partial_cmp(&(other.turn, other.spent))
+    }
+}
+
+/// Entry in the queue of coordinates to search in [`find_path_turns`], ordered like
+/// [`SearchEntry`] so that the cheapest entry is the greatest
+#[derive(Clone, Copy, Debug)]
+struct TurnEntry<C>
+{
+    cost: TurnCost,
+    coords: C,
+}
+
+impl<C> PartialEq for TurnEntry<C>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C> Eq for TurnEntry<C> {}
+
+impl<C> PartialOrd for TurnEntry<C>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for TurnEntry<C>
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.turn.cmp(&self.cost.turn)
+            .then_with(|| other.cost.spent.total_cmp(&self.cost.spent))
+    }
+}
+
+/// Searches for the path from `start` to `destination` taking the fewest turns for a unit with
+/// `budget` movement points each turn, see [`HexMap::find_path_turns`]
+pub(super) fn find_path_turns<C, T, F, R>(map: &HexMap<C, T>, start: C, destination: C, budget: f32, cost_fn: F) -> Option<TurnPath<C>>
+where C: Copy + Eq + Hash + HexCoords, F: Fn(C, C, &HexMap<C, T>) -> R, R: Into<MoveCost>
+{
+    let mut nodes: HashMap<C, (TurnCost, Option<C>)> = HashMap::new();
+    let mut searched_coords = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    nodes.insert(start, (TurnCost::START, None));
+    queue.push(TurnEntry{ cost: TurnCost::START, coords: start });
+    while let Some(entry) = queue.pop()
+    {
+        let coords = entry.coords;
+        if searched_coords.contains(&coords) || nodes[&coords].0 != entry.cost {
+            continue;
+        }
+        if coords == destination {
+            return Some(trace_turns(&nodes, destination, budget, searched_coords.len()));
+        }
+        searched_coords.insert(coords);
+        for direction in HexDirection::ALL
+        {
+            let next = coords.neighbor(direction);
+            if searched_coords.contains(&next) || map.get(next).is_none() || !map.can_cross(coords, direction) {
+                continue;
+            }
+            if let MoveCost::Cost(cost) = cost_fn(coords, next, map).into() {
+                let next_cost = entry.cost.after(cost, budget);
+                if nodes.get(&next).map_or(true, |(existing, _)| next_cost < *existing) {
+                    nodes.insert(next, (next_cost, Some(coords)));
+                    queue.push(TurnEntry{ cost: next_cost, coords: next });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Traces a path found by [`find_path_turns`] back from `dest`, splitting it into turns
+fn trace_turns<C>(nodes: &HashMap<C, (TurnCost, Option<C>)>, dest: C, budget: f32, nodes_expanded: usize) -> TurnPath<C>
+where C: Copy + Eq + Hash
+{
+    let mut tiles = Vec::new();
+    let mut costs = Vec::new();
+    let mut next_coords = Some(dest);
+    while let Some(c) = next_coords {
+        let (cost, prev_coords) = nodes[&c];
+        if prev_coords.is_some() {
+            tiles.push(c);
+            costs.push(cost);
+        }
+        next_coords = prev_coords;
+    }
+    tiles.reverse();
+    costs.reverse();
+    let turns = costs.iter().map(|cost| cost.turn).collect();
+    let remaining = costs.iter().map(|cost| budget - cost.spent).collect();
+    TurnPath{ tiles, turns, remaining, nodes_expanded }
+}
+
+/// Traces a path through `nodes` back from `dest` to the node without a previous node
+fn trace_nodes<C>(nodes: &HashMap<C, PathNode<C>>, dest: C, nodes_expanded: usize) -> HexPath<C>
+where C: Copy + Eq + Hash
+{
+    let mut tiles = Vec::new();
+    let mut costs = Vec::new();
+    let mut start = dest;
+    let mut next_coords = Some(dest);
+    while let Some(c) = next_coords {
+        let node = nodes.get(&c).unwrap();
+        if node.prev_coords.is_some() {
+            tiles.push(c);
+            costs.push(node.total_cost);
+        } else {
+            start = c;
+        }
+        next_coords = node.prev_coords;
+    }
+    tiles.reverse();
+    costs.reverse();
+    HexPath{ start, tiles, costs, nodes_expanded }
 }
 
 impl<C> Default for PathMap<C>
 {
     fn default() -> Self {
         Self{
-            coords_to_search: HashSet::new(),
+            coords_to_search: BinaryHeap::new(),
             searched_coords: HashSet::new(),
             nodes: HashMap::new(),
+            target: None,
         }
     }
 }
 
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests
 {
     use super::*;
     use crate::{axial, AxialCoords};
 
+    /// Whether the given coordinates have an entry in the `coords_to_search` queue
+    fn is_queued<C: PartialEq>(map: &PathMap<C>, coords: C) -> bool
+    {
+        map.coords_to_search.iter().any(|entry| entry.coords == coords)
+    }
+
     #[test]
     fn add_node()
     {
         let coords = axial!(1, 0);
         let mut map = PathMap::default();
-        assert_eq!(false, map.coords_to_search.contains(&coords));
+        assert_eq!(false, is_queued(&map, coords));
         assert_eq!(false, map.searched_coords.contains(&coords));
         assert_eq!(false, map.nodes.contains_key(&coords));
 
@@ -201,7 +834,7 @@ mod tests
             prev_coords: Some(axial!(0, 0))
         };
         map.add_node(coords, new_node.clone());
-        assert_eq!(true, map.coords_to_search.contains(&coords));
+        assert_eq!(true, is_queued(&map, coords));
         assert_eq!(false, map.searched_coords.contains(&coords));
         assert_eq!(true, map.nodes.contains_key(&coords));
         assert_eq!(&new_node, map.get_node(coords).unwrap());
@@ -278,6 +911,130 @@ mod tests
         );
     }
 
+    #[test]
+    fn eval_coords_blocked()
+    {
+        let mut pathmap = PathMap::default().starting_from(axial!(0, 0));
+        let mut map = HexMap::new();
+        map.insert_area(axial!(0, 0), 1, ());
+        map.insert_wall(axial!(0, 0), HexDirection::PosQ, Wall::Solid);
+        map.insert_wall(axial!(0, 0), HexDirection::NegQ, Wall::OneWay(HexDirection::PosQ));
+        map.insert_wall(axial!(0, 0), HexDirection::PosR, Wall::OneWay(HexDirection::PosR));
+
+        pathmap.eval_coords(axial!(0, 0), &map, |_, to, _| {
+            if to == axial!(0, -1) { None } else { Some(1.0) }
+        });
+        assert!(pathmap.get_node(axial!(1, 0)).is_none());
+        assert!(pathmap.get_node(axial!(-1, 0)).is_none());
+        assert!(pathmap.get_node(axial!(0, -1)).is_none());
+        assert!(pathmap.get_node(axial!(0, 1)).is_some());
+        assert!(pathmap.get_node(axial!(1, -1)).is_some());
+        assert!(pathmap.get_node(axial!(-1, 1)).is_some());
+    }
+
+    #[test]
+    fn wall_blocks()
+    {
+        assert!(Wall::Solid.blocks(HexDirection::NegS));
+        assert!(!Wall::OneWay(HexDirection::NegS).blocks(HexDirection::NegS));
+        assert!(Wall::OneWay(HexDirection::NegS).blocks(HexDirection::PosS));
+        assert_eq!(MoveCost::Cost(2.0), 2.0.into());
+        assert_eq!(MoveCost::Impassable, None.into());
+    }
+
+    #[test]
+    fn trace()
+    {
+        let mut pathmap = PathMap::default().starting_from(axial!(0, 0));
+        pathmap.insert_node(axial!(1, 0), PathNode{ total_cost: 1.5, prev_coords: Some(axial!(0, 0)) });
+        pathmap.insert_node(axial!(2, 0), PathNode{ total_cost: 2.0, prev_coords: Some(axial!(1, 0)) });
+        pathmap.set_coords_searched(axial!(0, 0));
+        pathmap.set_coords_searched(axial!(1, 0));
+
+        let path = pathmap.trace(axial!(2, 0));
+        assert_eq!(axial!(0, 0), path.start());
+        assert_eq!(axial!(2, 0), path.destination());
+        assert_eq!(&[axial!(1, 0), axial!(2, 0)], path.tiles());
+        assert_eq!(&[1.5, 2.0], path.cumulative_costs());
+        assert_eq!(2.0, path.total_cost());
+        assert_eq!(2, path.nodes_expanded());
+        assert_eq!(axial!(2, 0), path[1]);
+
+        let path = pathmap.trace(axial!(0, 0));
+        assert!(path.is_empty());
+        assert_eq!(axial!(0, 0), path.destination());
+        assert_eq!(0.0, path.total_cost());
+    }
+
+    #[test]
+    fn into_reachable()
+    {
+        let mut pathmap = PathMap::default().starting_from(axial!(0, 0));
+        pathmap.insert_node(axial!(1, 0), PathNode{ total_cost: 1.0, prev_coords: Some(axial!(0, 0)) });
+        pathmap.insert_node(axial!(2, 0), PathNode{ total_cost: 3.0, prev_coords: Some(axial!(1, 0)) });
+        pathmap.set_coords_searched(axial!(0, 0));
+        pathmap.set_coords_searched(axial!(1, 0));
+        assert_eq!(Some(3.0), pathmap.total_cost(axial!(2, 0)));
+
+        let reachable = pathmap.into_reachable(axial!(0, 0));
+        assert_eq!(2, reachable.len());
+        assert!(!reachable.contains(axial!(2, 0)));
+        assert_eq!(Some(1.0), reachable.cost(axial!(1, 0)));
+        assert_eq!(None, reachable.path_to(axial!(2, 0)));
+        assert_eq!(&[axial!(1, 0)], reachable.path_to(axial!(1, 0)).unwrap().tiles());
+    }
+
+    #[test]
+    fn turn_cost()
+    {
+        let cost = |turn, spent| TurnCost{ turn, spent };
+        // Fits in the current turn
+        assert_eq!(cost(0, 2.5), cost(0, 1.0).after(1.5, 3.0));
+        assert_eq!(cost(1, 3.0), cost(1, 1.0).after(2.0, 3.0));
+        // Doesn't fit, so the leftover point is lost and the move is made next turn
+        assert_eq!(cost(1, 2.0), cost(0, 2.0).after(2.0, 3.0));
+        assert_eq!(cost(1, 2.0), cost(0, 3.0).after(2.0, 3.0));
+        // Costs more than the whole budget, which uses up a whole turn from the start of one
+        assert_eq!(cost(0, 3.0), TurnCost::START.after(5.0, 3.0));
+        assert_eq!(cost(1, 3.0), cost(0, 3.0).after(5.0, 3.0));
+        assert_eq!(cost(2, 3.0), cost(1, 1.0).after(5.0, 3.0));
+        // Points spent late in a long path are as precise as in the first turn
+        assert_eq!(cost(10_000_000, 0.75), cost(10_000_000, 0.5).after(0.25, 1.0));
+        assert_eq!(cost(10_000_001, 0.5), cost(10_000_000, 0.75).after(0.5, 1.0));
+        // Fewer turns is always cheaper, however many points are spent
+        assert!(cost(1, 3.0) < cost(2, 0.5));
+        assert!(cost(2, 0.5) < cost(2, 1.0));
+    }
+
+    #[test]
+    fn trace_joined()
+    {
+        let mut map = HexMap::new();
+        map.insert_area(axial!(0, 0), 3, ());
+        let cost_fn = |_, to: AxialCoords, _: &HexMap<AxialCoords, ()>| if to.q > 0 { 2.0 } else { 1.0 };
+        let mut forward = PathMap::default().starting_from(axial!(-2, 0));
+        let mut backward = PathMap::default().starting_from(axial!(2, 0));
+        for coords in [axial!(-2, 0), axial!(-1, 0)]
+        {
+            forward.eval_coords(coords, &map, cost_fn);
+            forward.set_coords_searched(coords);
+        }
+        for coords in [axial!(2, 0), axial!(1, 0)]
+        {
+            backward.eval_coords_reverse(coords, &map, cost_fn);
+            backward.set_coords_searched(coords);
+        }
+        assert_eq!(Some(axial!(1, 0)), backward.get_node(axial!(0, 0)).unwrap().prev_coords);
+        // Moves into (1, 0) and (2, 0) cost more than the move into (0, 0)
+        assert_eq!(Some(4.0), backward.total_cost(axial!(0, 0)));
+
+        let path = forward.trace_joined(&backward, axial!(0, 0));
+        assert_eq!(axial!(-2, 0), path.start());
+        assert_eq!(&[axial!(-1, 0), axial!(0, 0), axial!(1, 0), axial!(2, 0)], path.tiles());
+        assert_eq!(&[1.0, 2.0, 4.0, 6.0], path.cumulative_costs());
+        assert_eq!(4, path.nodes_expanded());
+    }
+
     #[test]
     fn get_next_node()
     {
@@ -303,12 +1060,56 @@ mod tests
         assert_eq!(None, map.get_next_node());
     }
 
+    #[test]
+    fn towards()
+    {
+        let next_towards = |min_step_cost| {
+            let mut map = PathMap::default();
+            // The cheaper node is further from the destination, so with a high enough minimum
+            // step cost the more expensive one is searched first
+            map.insert_node(axial!(-1, 0), PathNode{ total_cost: 1.0, prev_coords: Some(axial!(0, 0)) });
+            map.insert_node(axial!(1, 0), PathNode{ total_cost: 2.0, prev_coords: Some(axial!(0, 0)) });
+            map.towards(axial!(3, 0), min_step_cost).get_next_node()
+        };
+        assert_eq!(Some(axial!(-1, 0)), next_towards(0.0));
+        assert_eq!(Some(axial!(-1, 0)), next_towards(0.4));
+        assert_eq!(Some(axial!(1, 0)), next_towards(0.6));
+        assert_eq!(None, PathMap::default().towards(axial!(0, 0), 1.0).get_next_node());
+    }
+
+    /// Ensures that outdated entries in the queue are skipped, and that searched coordinates
+    /// aren't evaluated again
+    #[test]
+    fn lazy_deletion()
+    {
+        let mut pathmap = PathMap::default();
+        pathmap.insert_node(axial!(1, 0), PathNode{ total_cost: 3.0, prev_coords: Some(axial!(0, 0)) });
+        pathmap.insert_node(axial!(2, 0), PathNode{ total_cost: 2.0, prev_coords: Some(axial!(0, 0)) });
+        pathmap.insert_node(axial!(1, 0), PathNode{ total_cost: 1.0, prev_coords: Some(axial!(0, 0)) });
+        assert_eq!(3, pathmap.coords_to_search.len());
+
+        assert_eq!(Some(axial!(1, 0)), pathmap.get_next_node());
+        pathmap.set_coords_searched(axial!(1, 0));
+        assert_eq!(Some(axial!(2, 0)), pathmap.get_next_node());
+        pathmap.set_coords_searched(axial!(2, 0));
+        // The entry for (1, 0) at its old cost is still in the queue, but is skipped
+        assert_eq!(None, pathmap.get_next_node());
+        assert!(pathmap.coords_to_search.is_empty());
+        assert_eq!(2, pathmap.searched_count());
+
+        let mut map = HexMap::new();
+        map.insert_area(axial!(0, 0), 2, ());
+        pathmap.eval_coords(axial!(2, 0), &map, |_, _, _| 0.5);
+        assert_eq!(1.0, pathmap.get_node(axial!(1, 0)).unwrap().total_cost);
+        assert_eq!(2.5, pathmap.get_node(axial!(2, -1)).unwrap().total_cost);
+    }
+
     #[test]
     fn insert_node()
     {
         let coords = axial!(0, 0);
         let mut map = PathMap::default();
-        assert_eq!(false, map.coords_to_search.contains(&coords));
+        assert_eq!(false, is_queued(&map, coords));
         assert_eq!(false, map.searched_coords.contains(&coords));
         assert_eq!(false, map.nodes.contains_key(&coords));
 
@@ -318,7 +1119,7 @@ mod tests
             prev_coords: None,
         };
         map.insert_node(coords, node.clone());
-        assert_eq!(true, map.coords_to_search.contains(&coords), "`map.coords_to_search` set did not contain the added node");
+        assert_eq!(true, is_queued(&map, coords), "`map.coords_to_search` set did not contain the added node");
         assert_eq!(false, map.searched_coords.contains(&coords), "`map.searched_coords` set contains the added node when it should not");
         assert_eq!(true, map.nodes.contains_key(&coords), "`map.nodes` did not contain the added node");
         assert_eq!(&node, map.nodes.get(&coords).unwrap(), "Node at coordinates did not match the node added");
@@ -329,7 +1130,7 @@ mod tests
             prev_coords: Some(axial!(1, 0)),
         };
         map.insert_node(coords, new_node.clone());
-        assert_eq!(true, map.coords_to_search.contains(&coords));
+        assert_eq!(true, is_queued(&map, coords));
         assert_eq!(false, map.searched_coords.contains(&coords), "`map.searched_coords` set contains the added node when it should not");
         assert_eq!(true, map.nodes.contains_key(&coords));
         assert_eq!(&node, map.nodes.get(&coords).unwrap(), "Node was updated with new higher cost node when it should not have been");
@@ -340,7 +1141,7 @@ mod tests
             prev_coords: Some(axial!(1, 0)),
         };
         map.insert_node(coords, new_node.clone());
-        assert_eq!(true, map.coords_to_search.contains(&coords));
+        assert_eq!(true, is_queued(&map, coords));
         assert_eq!(false, map.searched_coords.contains(&coords), "`map.searched_coords` set contains the added node when it should not");
         assert_eq!(true, map.nodes.contains_key(&coords));
         assert_eq!(&new_node, map.nodes.get(&coords).unwrap(), "Existing node was not updated with the new lower cost node");
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::{CoordInt, HexCoords, HexVertex};
+
+#[cfg(feature="bevy")]
+use bevy::prelude::Resource;
+
+
+/// A map of data stored on the vertices where tiles meet, such as settlements on tile corners
+///
+/// Companion to [`HexMap`](crate::HexMap), indexed by [`HexVertex`] instead of tile coordinates.
+/// Since vertices are always stored in canonical form, the data on a vertex can be looked up from
+/// any of the three tiles meeting at it.
+#[cfg_attr(feature="bevy", derive(Resource))]
+#[derive(Deserialize, Serialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct VertexMap<T, I = isize>
+where I: CoordInt
+{
+    #[serde(with = "super::entries")]
+    map: HashMap<HexVertex<I>, T>
+}
+
+impl<T, I> VertexMap<T, I>
+where I: CoordInt
+{
+	/// Creates a new, empty map
+    pub fn new() -> Self
+    {
+        Self{ map: HashMap::new() }
+    }
+
+	/// Gets the data on the given vertex, if there is any
+    pub fn get(&self, vertex: HexVertex<I>) -> Option<&T>
+    {
+        self.map.get(&vertex)
+    }
+
+	/// Gets the data on the given vertex mutably, if there is any
+    pub fn get_mut(&mut self, vertex: HexVertex<I>) -> Option<&mut T>
+    {
+        self.map.get_mut(&vertex)
+    }
+
+	/// Inserts data on the given vertex
+    pub fn insert(&mut self, vertex: HexVertex<I>, value: T)
+    {
+        self.map.insert(vertex, value);
+    }
+
+	/// Removes the data on the given vertex, returning it if there was any
+    pub fn remove(&mut self, vertex: HexVertex<I>) -> Option<T>
+    {
+        self.map.remove(&vertex)
+    }
+
+	/// Gets the data on each of the six corners of `tile`, in the same order as
+	/// [`Orientation::tile_corners`](crate::Orientation::tile_corners)
+    pub fn tile_vertices<C>(&self, tile: C) -> [Option<&T>;6]
+    where C: HexCoords<Int = I>
+    {
+        HexVertex::of_tile(tile).map(|vertex| self.get(vertex))
+    }
+
+	/// Returns an iterator of all the Vertex/Value (Key/Value) pairs in this map
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, HexVertex<I>, T>
+    {
+        self.map.iter()
+    }
+}
+
+impl<T, I> Default for VertexMap<T, I>
+where I: CoordInt
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, HexDirection, OddRCoords};
+
+    #[test]
+    fn get_and_insert()
+    {
+        let mut map: VertexMap<&str> = VertexMap::new();
+        map.insert(HexVertex::new(axial!(0, 0), 2), "village");
+        assert_eq!(Some(&"village"), map.get(HexVertex::new(axial!(0, 0), 2)));
+        // The same vertex seen from the other two tiles
+        assert_eq!(Some(&"village"), map.get(HexVertex::new(axial!(1, -1), 0)));
+        assert_eq!(Some(&"village"), map.get(HexVertex::new(axial!(1, 0), 4)));
+        assert_eq!(None, map.get(HexVertex::new(axial!(0, 0), 3)));
+
+        *map.get_mut(HexVertex::new(axial!(1, 0), 4)).unwrap() = "city";
+        assert_eq!(Some(&"city"), map.get(HexVertex::new(axial!(0, 0), 2)));
+        assert_eq!(1, map.iter().count());
+    }
+
+    #[test]
+    fn remove()
+    {
+        let mut map: VertexMap<&str> = VertexMap::new();
+        map.insert(HexVertex::new(axial!(0, 0), 2), "village");
+        assert_eq!(None, map.remove(HexVertex::new(axial!(0, 0), 3)));
+        // Removed through one of the other tiles sharing the vertex
+        assert_eq!(Some("village"), map.remove(HexVertex::new(axial!(1, -1), 0)));
+        assert_eq!(None, map.get(HexVertex::new(axial!(0, 0), 2)));
+        assert_eq!(None, map.remove(HexVertex::new(axial!(0, 0), 2)));
+    }
+
+    #[test]
+    fn tile_vertices()
+    {
+        let mut map: VertexMap<u8, i32> = VertexMap::default();
+        let tile = OddRCoords::<i32>::new(2, 1);
+        map.insert(HexVertex::new(tile, 5), 1);
+        map.insert(HexVertex::new(tile.neighbor(HexDirection::PosQ), 5), 2);
+        assert_eq!([None, Some(&2), None, None, None, Some(&1)], map.tile_vertices(tile));
+    }
+
+    /// Ensures that vertices are stored in canonical form when deserializing, even if they weren't
+    /// written that way
+    #[test]
+    fn serde()
+    {
+        let mut map: VertexMap<String> = VertexMap::new();
+        map.insert(HexVertex::new(axial!(0, 0), 0), "village".to_string());
+        map.insert(HexVertex::new(axial!(-1, 3), 4), "city".to_string());
+        let json = serde_json::to_string(&map).unwrap();
+        let deserialized: VertexMap<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(2, deserialized.iter().count());
+        for (vertex, value) in map.iter()
+        {
+            assert_eq!(Some(value), deserialized.get(*vertex));
+        }
+
+        let json = r#"{"map":[[{"tile":{"q":1,"r":0},"corner":4},"port"]]}"#;
+        let deserialized: VertexMap<String> = serde_json::from_str(json).unwrap();
+        let vertex = HexVertex::new(axial!(0, 0), 2);
+        assert_eq!(Some(&"port".to_string()), deserialized.get(vertex));
+        assert_eq!(Some(&vertex), deserialized.iter().next().map(|(vertex, _)| vertex));
+    }
+}
@@ -0,0 +1,334 @@
+use std::{collections::{hash_set, HashSet}, hash::Hash, ops::{BitAnd, BitOr, Sub}};
+use serde::{Deserialize, Serialize};
+use crate::{CoordInt, Cube, HexCoords};
+
+
+/// A set of tiles, such as the tiles in range of a unit or the tiles covered by a structure
+///
+/// Regions can be built from any of the shape generators in [`HexCoords`], either by collecting
+/// their iterators or converting their [`Vec`]s, and combined with the usual set operations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "C: Eq + Hash + Serialize + for<'a> Deserialize<'a>")]
+pub struct HexRegion<C>
+where C: Eq + Hash
+{
+    tiles: HashSet<C>,
+}
+
+impl<C> HexRegion<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    /// Creates a new, empty region
+    pub fn new() -> Self
+    {
+        Self{ tiles: HashSet::new() }
+    }
+
+    /// Creates a region containing every tile within `radius` steps of `center`
+    pub fn area(center: C, radius: usize) -> Self
+    {
+        C::area_iter(center, radius).collect()
+    }
+
+    /// Creates a region containing every tile exactly `radius` steps away from `center`
+    pub fn ring(center: C, radius: usize) -> Self
+    {
+        C::ring_iter(center, radius).collect()
+    }
+
+    /// Creates a region containing every tile that is within range of all of the given
+    /// `(center, radius)` pairs.
+    ///
+    /// Rather than building each area and intersecting them, this intersects the bounds of each
+    /// area along the three cube axes and only goes through the tiles within those bounds. If no
+    /// ranges are given, the region is empty.
+    ///
+    /// <https://www.redblobgames.com/grids/hexagons/#range-intersection>
+    pub fn range_intersection<R>(ranges: R) -> Self
+    where R: IntoIterator<Item = (C, usize)>
+    {
+        // The bounds are worked out in i128 and clamped to the integer type, as a tile near its
+        // limits or a large radius can put them out of range
+        let (int_min, int_max) = (C::Int::MIN.to_i128(), C::Int::MAX.to_i128());
+        let mut ranges = ranges.into_iter().map(|(center, radius)| {
+            let center = center.to_cube();
+            let radius = radius as i128;
+            // Minimum and maximum of each of q, r and s
+            [center.q, center.r, center.s].map(|value| {
+                let value = value.to_i128();
+                ((value - radius).max(int_min), (value + radius).min(int_max))
+            })
+        });
+        let Some(mut bounds) = ranges.next() else {
+            return Self::new();
+        };
+        for range in ranges
+        {
+            for (bound, other) in bounds.iter_mut().zip(range)
+            {
+                *bound = (bound.0.max(other.0), bound.1.min(other.1));
+            }
+        }
+        let [(q_min, q_max), (r_min, r_max), (s_min, s_max)] = bounds;
+        if q_min > q_max {
+            return Self::new();
+        }
+        let mut tiles = HashSet::new();
+        // Bounds are inclusive and can sit at the limits of the integer type, so the bounds of each
+        // column are worked out in i128 and the loops stop on their last value instead of stepping past
+        let (mut q, q_max) = (C::Int::from_i128(q_min), C::Int::from_i128(q_max));
+        loop
+        {
+            let neg_q = -q.to_i128();
+            let r_start = r_min.max(neg_q - s_max);
+            let r_end = r_max.min(neg_q - s_min);
+            if r_start <= r_end
+            {
+                let (mut r, r_end) = (C::Int::from_i128(r_start), C::Int::from_i128(r_end));
+                loop
+                {
+                    let s = C::Int::from_i128(neg_q - r.to_i128());
+                    tiles.insert(C::from_cube(Cube{ q, r, s }));
+                    if r == r_end {
+                        break;
+                    }
+                    r = r + C::Int::ONE;
+                }
+            }
+            if q == q_max {
+                break;
+            }
+            q = q + C::Int::ONE;
+        }
+        Self{ tiles }
+    }
+
+    /// Number of tiles in this region
+    pub fn len(&self) -> usize
+    {
+        self.tiles.len()
+    }
+
+    /// Whether this region contains no tiles
+    pub fn is_empty(&self) -> bool
+    {
+        self.tiles.is_empty()
+    }
+
+    /// Whether the given tile is part of this region
+    pub fn contains(&self, coords: C) -> bool
+    {
+        self.tiles.contains(&coords)
+    }
+
+    /// Adds a tile to this region, returning whether it wasn't already part of it
+    pub fn insert(&mut self, coords: C) -> bool
+    {
+        self.tiles.insert(coords)
+    }
+
+    /// Removes a tile from this region, returning whether it was part of it
+    pub fn remove(&mut self, coords: C) -> bool
+    {
+        self.tiles.remove(&coords)
+    }
+
+    /// Creates a region with the tiles that are in either this region or `other`
+    pub fn union(&self, other: &Self) -> Self
+    {
+        self.tiles.union(&other.tiles).copied().collect()
+    }
+
+    /// Creates a region with the tiles that are in both this region and `other`
+    pub fn intersection(&self, other: &Self) -> Self
+    {
+        self.tiles.intersection(&other.tiles).copied().collect()
+    }
+
+    /// Creates a region with the tiles that are in this region but not in `other`
+    pub fn difference(&self, other: &Self) -> Self
+    {
+        self.tiles.difference(&other.tiles).copied().collect()
+    }
+
+    /// Returns an iterator over the tiles in this region, in no particular order
+    pub fn iter(&self) -> hash_set::Iter<'_, C>
+    {
+        self.tiles.iter()
+    }
+}
+
+impl<C> Default for HexRegion<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// TRAITS: SET OPERATIONS ----------------------------------------------------------------------- //
+
+impl<C> BitOr<&HexRegion<C>> for &HexRegion<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    type Output = HexRegion<C>;
+
+    fn bitor(self, rhs: &HexRegion<C>) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<C> BitAnd<&HexRegion<C>> for &HexRegion<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    type Output = HexRegion<C>;
+
+    fn bitand(self, rhs: &HexRegion<C>) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl<C> Sub<&HexRegion<C>> for &HexRegion<C>
+where C: Copy + Eq + Hash + HexCoords
+{
+    type Output = HexRegion<C>;
+
+    fn sub(self, rhs: &HexRegion<C>) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+// TRAITS: CONVERSION --------------------------------------------------------------------------- //
+
+impl<C> FromIterator<C> for HexRegion<C>
+where C: Eq + Hash
+{
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+        Self{ tiles: iter.into_iter().collect() }
+    }
+}
+
+impl<C> Extend<C> for HexRegion<C>
+where C: Eq + Hash
+{
+    fn extend<T: IntoIterator<Item = C>>(&mut self, iter: T) {
+        self.tiles.extend(iter)
+    }
+}
+
+impl<C> From<Vec<C>> for HexRegion<C>
+where C: Eq + Hash
+{
+    fn from(value: Vec<C>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<C> IntoIterator for HexRegion<C>
+where C: Eq + Hash
+{
+    type Item = C;
+    type IntoIter = hash_set::IntoIter<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiles.into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &'a HexRegion<C>
+where C: Eq + Hash
+{
+    type Item = &'a C;
+    type IntoIter = hash_set::Iter<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiles.iter()
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{axial, cube, Axial, AxialCoords, OddRCoords};
+
+    #[test]
+    fn from_shapes()
+    {
+        let center = axial!(1, -2);
+        let area = HexRegion::area(center, 3);
+        assert_eq!(37, area.len());
+        assert_eq!(area, HexRegion::from(AxialCoords::area(center, 3)));
+        let ring = HexRegion::ring(center, 3);
+        assert_eq!(18, ring.len());
+        assert_eq!(ring, AxialCoords::ring(center, 3).into());
+        assert!(ring.iter().all(|coords| area.contains(*coords)));
+        assert!(HexRegion::<AxialCoords>::new().is_empty());
+    }
+
+    #[test]
+    fn set_operations()
+    {
+        let a = HexRegion::area(cube!(0, 0, 0), 2);
+        let b = HexRegion::area(cube!(2, 0, -2), 2);
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        let difference = a.difference(&b);
+        assert_eq!(union, &a | &b);
+        assert_eq!(intersection, &a & &b);
+        assert_eq!(difference, &a - &b);
+        assert_eq!(a.len() + b.len(), union.len() + intersection.len());
+        assert_eq!(a.len(), difference.len() + intersection.len());
+        assert!(intersection.contains(cube!(1, 0, -1)));
+        assert!(difference.contains(cube!(-1, 0, 1)));
+        assert!(!difference.contains(cube!(1, 0, -1)));
+    }
+
+    /// Ensures that intersecting ranges through their bounds produces the same tiles as
+    /// intersecting their areas
+    #[test]
+    fn range_intersection()
+    {
+        let ranges = [(OddRCoords::new(0, 0), 3), (OddRCoords::new(3, 2), 2), (OddRCoords::new(1, -1), 4)];
+        let expected = ranges.iter()
+            .map(|(center, radius)| HexRegion::area(*center, *radius))
+            .reduce(|a, b| a.intersection(&b))
+            .unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, HexRegion::range_intersection(ranges));
+
+        assert_eq!(HexRegion::area(axial!(2, 2), 2), HexRegion::range_intersection([(axial!(2, 2), 2)]));
+        assert!(HexRegion::range_intersection([(axial!(0, 0), 1), (axial!(5, 0), 1)]).is_empty());
+        assert!(HexRegion::<AxialCoords>::range_intersection([]).is_empty());
+
+        // Bounds at the largest value of the integer type
+        let region = HexRegion::range_intersection([(Axial::<i8>::new(126, -126), 1)]);
+        assert_eq!(7, region.len());
+        assert!(region.contains(Axial::new(127, -127)));
+        let region = HexRegion::range_intersection([(Axial::<i8>::new(-126, 126), 1)]);
+        assert_eq!(7, region.len());
+        assert!(region.contains(Axial::new(-127, 127)));
+        let region = HexRegion::range_intersection([(Axial::<i8>::new(126, -126), 2)]);
+        assert_eq!(16, region.len());
+        assert!(region.contains(Axial::new(127, -128)));
+        assert!(region.contains(Axial::new(124, -126)));
+
+        // Radius larger than the largest value of the integer type
+        let region = HexRegion::range_intersection([(Axial::<i8>::ZERO, 200)]);
+        assert!(region.contains(Axial::new(127, -127)));
+        assert!(region.contains(Axial::new(-128, 127)));
+        assert!(region.contains(Axial::new(0, -127)));
+        let region = HexRegion::range_intersection([(Axial::<i8>::ZERO, 200), (Axial::new(100, 0), 1)]);
+        assert_eq!(HexRegion::area(Axial::new(100, 0), 1), region);
+    }
+
+    #[test]
+    fn serde()
+    {
+        let region = HexRegion::ring(axial!(0, 0), 1);
+        let json = serde_json::to_string(&region).unwrap();
+        assert_eq!(region, serde_json::from_str(&json).unwrap());
+    }
+}
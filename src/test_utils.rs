@@ -0,0 +1,11 @@
+//! Helpers shared between the unit tests of different modules
+
+
+/// Asserts that two world positions are equal, within a small margin of error
+pub(crate) fn assert_world_eq(expected: (f32, f32), actual: (f32, f32))
+{
+    assert!(
+        (expected.0 - actual.0).abs() < 0.0001 && (expected.1 - actual.1).abs() < 0.0001,
+        "Expected world position {:?}, got {:?}", expected, actual
+    );
+}